@@ -0,0 +1,120 @@
+use std::fmt;
+
+// Where and why a puzzle input couldn't be parsed. Parsers that only see a
+// single line report the column, and whoever split the input into lines fills
+// in the line number, the line itself, and finally the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    pub source_line: Option<String>,
+}
+
+impl ParseError {
+    // `column` is 1-based. An empty `found` means the line ended early.
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+            source_line: None,
+        }
+    }
+
+    // An error at the first character of `found` within `line`, which must be
+    // a subslice of it.
+    pub fn at(line: &str, found: &str, expected: impl Into<String>) -> Self {
+        let column = found.as_ptr() as usize - line.as_ptr() as usize + 1;
+        ParseError::new(column, expected, found)
+    }
+
+    pub fn at_line(mut self, number: usize, line: &str) -> Self {
+        self.line = number;
+        self.source_line = Some(line.to_string());
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file, self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+
+        if self.found.is_empty() {
+            write!(f, "expected {}, found the end of the line", self.expected)?;
+        } else {
+            write!(f, "expected {}, found \"{}\"", self.expected, self.found)?;
+        }
+
+        if let Some(source_line) = &self.source_line {
+            write!(
+                f,
+                "\n    {}\n    {:>width$}",
+                source_line,
+                "^",
+                width = self.column
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Points a parse error at the file its input came from, leaving any other
+// error untouched.
+pub fn in_file(err: anyhow::Error, file: &str) -> anyhow::Error {
+    match err.downcast::<ParseError>() {
+        Ok(parse_error) => parse_error.in_file(file).into(),
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let line = "move 1 from x to 1";
+        let err = ParseError::at(line, &line[12..13], "a stack number")
+            .at_line(5, line)
+            .in_file("day5/input.txt");
+        assert_eq!(
+            "day5/input.txt:5:13: expected a stack number, found \"x\"\n    move 1 from x to 1\n                ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_end_of_line() {
+        let err = ParseError::new(5, "a number", "").at_line(2, "addx");
+        assert_eq!(
+            "line 2, column 5: expected a number, found the end of the line\n    addx\n        ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_in_file() {
+        let err = in_file(ParseError::new(1, "U", "X").into(), "day9/input.txt");
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(Some("day9/input.txt".to_string()), err.file);
+
+        let err = in_file(anyhow::anyhow!("not a parse error"), "day9/input.txt");
+        assert_eq!("not a parse error", err.to_string());
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+// A position or offset on a grid. y grows downwards, like the rows of the
+// puzzle input, so `Direction::Up` is negative y.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // Distance when diagonal steps count as one, like a king in chess.
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // Whether the two overlap or are next to each other, diagonals included.
+    pub fn is_touching(self, other: Point) -> bool {
+        self.chebyshev(other) <= 1
+    }
+
+    // Each coordinate clamped to -1, 0 or 1.
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    // One step, diagonals allowed, straight towards `target`.
+    pub fn step_towards(self, target: Point) -> Point {
+        self + (target - self).signum()
+    }
+
+    // A quarter turn about the origin, as seen on screen with y down.
+    pub fn rotate_right(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    pub fn rotate_left(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, n: i64) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.step()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+// The eight compass directions, clockwise from up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub fn step(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    fn turn(self, eighths: usize) -> Direction {
+        let i = Direction::ALL.iter().position(|d| *d == self).unwrap();
+        Direction::ALL[(i + eighths) % 8]
+    }
+
+    // A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Point {
+        direction.step()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(4, 2);
+        assert_eq!(7, a.manhattan(b));
+        assert_eq!(4, a.chebyshev(b));
+        assert!(Point::new(3, 1).is_touching(b));
+        assert!(!a.is_touching(b));
+    }
+
+    #[test]
+    fn test_step_towards() {
+        assert_eq!(
+            Point::new(2, 1),
+            Point::new(1, 0).step_towards(Point::new(3, 2))
+        );
+        assert_eq!(
+            Point::new(2, 0),
+            Point::new(1, 0).step_towards(Point::new(3, 0))
+        );
+        assert_eq!(
+            Point::new(1, 0),
+            Point::new(1, 0).step_towards(Point::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.turn_right().step(),
+                direction.step().rotate_right()
+            );
+            assert_eq!(direction.turn_left().step(), direction.step().rotate_left());
+            assert_eq!(direction.opposite().step(), -direction.step());
+        }
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(
+            Point::new(4, -1),
+            Point::new(1, 1) + Direction::UpRight.step() * 2 + Direction::Right
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::ParseError,
+    geometry::{Direction, Point},
+    input::InputError,
+};
+
+// A rectangle of cells, stored row by row and indexed by `Point` with (0, 0)
+// in the top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "cells don't fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    // Parses one cell per character, skipping blank lines. Every row has to be
+    // as wide as the first.
+    pub fn parse_with(
+        text: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, InputError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (number, line) in crate::input::numbered_lines(text) {
+            let row = line.trim();
+            let mut row_width = 0;
+
+            for (i, c) in row.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(line, &row[i..i + c.len_utf8()], expected).at_line(number, line)
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(InputError::Ragged {
+                        line: number,
+                        expected: width,
+                        found: row_width,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    // Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new((i % width) as i64, (i / width) as i64))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    // The first point, row by row, whose cell matches.
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(p, _)| p)
+    }
+
+    // The points above, right of, below and left of `p` that are in the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .iter()
+            .map(move |direction| p + *direction)
+            .filter(|n| self.contains(*n))
+    }
+
+    // Like `neighbours4`, with the diagonals too.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |direction| p + *direction)
+            .filter(|n| self.contains(*n))
+    }
+
+    // The cells seen walking from `from` (not included) by `step`, a
+    // `Direction` or any other offset, until falling off the edge of the grid.
+    pub fn ray(
+        &self,
+        from: Point,
+        step: impl Into<Point>,
+    ) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let step = step.into();
+        let mut p = from;
+        std::iter::from_fn(move || {
+            p += step;
+            self.get(p).map(|cell| (p, cell))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl Grid<char> {
+    pub fn from_chars(text: &str) -> Result<Self, InputError> {
+        Grid::parse_with(text, "a character", Some)
+    }
+}
+
+impl Grid<u32> {
+    pub fn from_digits(text: &str) -> Result<Self, InputError> {
+        Grid::parse_with(text, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{} is outside the {}x{} grid", p, width, height),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_digits() {
+        let grid = Grid::from_digits("303\n255\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(5, grid[Point::new(2, 1)]);
+        assert_eq!(None, grid.get(Point::new(3, 1)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+
+        assert!(matches!(
+            Grid::from_digits("303\n25\n"),
+            Err(InputError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            Grid::from_digits("303\n2x5\n"),
+            Err(InputError::Parse(ParseError {
+                line: 2,
+                column: 2,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from_chars("abc\ndef\nghi\n").unwrap();
+        let corner: Vec<char> = grid.neighbours4(Point::ORIGIN).map(|p| grid[p]).collect();
+        assert_eq!(vec!['b', 'd'], corner);
+
+        let middle: String = grid
+            .neighbours8(Point::new(1, 1))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!("bcfihgda", middle);
+    }
+
+    #[test]
+    fn test_ray_and_views() {
+        let grid = Grid::from_chars("abc\ndef\nghi\n").unwrap();
+        let ray: String = grid
+            .ray(Point::new(2, 0), Direction::DownLeft)
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!("eg", ray);
+
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("cfi", grid.column(2).collect::<String>());
+        assert_eq!(Some(Point::new(1, 2)), grid.position(|c| *c == 'h'));
+        assert_eq!("abc\ndef\nghi", grid.to_string());
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+use crate::error::ParseError;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("couldn't read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("couldn't find input for day {day}, tried:\n  {}", .tried.join("\n  "))]
+    NotFound { day: u32, tried: Vec<String> },
+    #[error("expected a single line of input, found none")]
+    Empty,
+    #[error("expected a single line of input, found {0}")]
+    MultipleLines(usize),
+    #[error("line {line} is {found} wide, expected {expected} like the first line")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+pub fn read(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    std::fs::read_to_string(path).map_err(|source| InputError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub struct ResolvedInput {
+    pub source: String,
+    pub text: String,
+}
+
+// Where the puzzles live in this repository, so the runner works from any
+// working directory.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+// Finds a day's input, in order: the --input flag ("-" meaning stdin), then
+// $AOC_INPUT_DIR, then the input bundled in the repository. Stdin is never
+// read unless asked for, since CI often leaves it open without writing to it.
+pub fn resolve(day: u32, flag: Option<&str>) -> Result<ResolvedInput, InputError> {
+    let input_dir = std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    resolve_from(day, flag, input_dir, &mut std::io::stdin())
+}
+
+fn resolve_from(
+    day: u32,
+    flag: Option<&str>,
+    input_dir: Option<PathBuf>,
+    stdin: &mut dyn Read,
+) -> Result<ResolvedInput, InputError> {
+    let mut tried = vec![];
+
+    if let Some(flag) = flag {
+        if flag == "-" {
+            return read_stdin(stdin);
+        }
+
+        let path = PathBuf::from(flag);
+        if path.is_file() {
+            return from_path(&path);
+        }
+        tried.push(format!("--input {} (no such file)", path.display()));
+        return Err(InputError::NotFound { day, tried });
+    }
+    tried.push("--input (not given, use \"-\" for stdin)".to_string());
+
+    match input_dir {
+        Some(dir) => {
+            for path in [
+                dir.join(format!("day{}.txt", day)),
+                dir.join(format!("day{}", day)).join("input.txt"),
+            ] {
+                if path.is_file() {
+                    return from_path(&path);
+                }
+                tried.push(format!(
+                    "${} {} (no such file)",
+                    INPUT_DIR_VAR,
+                    path.display()
+                ));
+            }
+        }
+        None => tried.push(format!("${} (not set)", INPUT_DIR_VAR)),
+    }
+
+    let bundled = workspace_dir()
+        .join(format!("day{}", day))
+        .join("input.txt");
+    if bundled.is_file() {
+        return from_path(&bundled);
+    }
+    tried.push(format!("{} (no such file)", bundled.display()));
+
+    Err(InputError::NotFound { day, tried })
+}
+
+fn from_path(path: &Path) -> Result<ResolvedInput, InputError> {
+    Ok(ResolvedInput {
+        source: path.display().to_string(),
+        text: read(path)?,
+    })
+}
+
+fn read_stdin(stdin: &mut dyn Read) -> Result<ResolvedInput, InputError> {
+    let mut text = String::new();
+    stdin
+        .read_to_string(&mut text)
+        .map_err(|source| InputError::Io {
+            path: PathBuf::from("-"),
+            source,
+        })?;
+
+    Ok(ResolvedInput {
+        source: "<stdin>".to_string(),
+        text,
+    })
+}
+
+// Every non-empty line, with leading whitespace kept. Day 5's crate
+// diagrams and day 11's monkey blocks rely on the indentation.
+pub fn raw_lines(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+// Every non-empty line along with its 1-based line number, so parse errors can
+// point at the right place even when blank lines were skipped.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line))
+}
+
+// Parses every non-empty line with `FromStr`, filling in the line number of
+// the first one that fails.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    numbered_lines(input)
+        .map(|(number, line)| {
+            line.parse()
+                .map_err(|e: ParseError| e.at_line(number, line))
+        })
+        .collect()
+}
+
+pub fn trimmed_lines(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+// Groups of numbered lines separated by one or more blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut result = vec![];
+    let mut current: Vec<(usize, &str)> = vec![];
+
+    for (number, line) in numbered_lines(input) {
+        if current
+            .last()
+            .is_some_and(|(previous, _)| number > previous + 1)
+        {
+            result.push(std::mem::take(&mut current));
+        }
+        current.push((number, line));
+    }
+
+    if !current.is_empty() {
+        result.push(current);
+    }
+
+    result
+}
+
+pub fn single_line(input: &str) -> Result<String, InputError> {
+    let lines = trimmed_lines(input);
+    match lines.len() {
+        0 => Err(InputError::Empty),
+        1 => Ok(lines[0].clone()),
+        n => Err(InputError::MultipleLines(n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_from_flag() {
+        let path = workspace_dir().join("day6").join("sample.txt");
+        let input = resolve_from(6, path.to_str(), None, &mut "".as_bytes()).unwrap();
+        assert_eq!(path.display().to_string(), input.source);
+        assert!(input.text.starts_with("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+    }
+
+    #[test]
+    fn test_resolve_from_stdin() {
+        let mut stdin = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".as_bytes();
+        let input = resolve_from(6, Some("-"), None, &mut stdin).unwrap();
+        assert_eq!("<stdin>", input.source);
+        assert_eq!("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", input.text);
+    }
+
+    #[test]
+    fn test_resolve_from_input_dir() {
+        let input = resolve_from(6, None, Some(workspace_dir()), &mut "".as_bytes()).unwrap();
+        assert!(input.source.ends_with("day6/input.txt"));
+    }
+
+    #[test]
+    fn test_resolve_lists_everything_tried() {
+        let mut stdin = "".as_bytes();
+        let err = resolve_from(99, None, Some(PathBuf::from("/nowhere")), &mut stdin)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.starts_with("couldn't find input for day 99, tried:"));
+        assert!(err.contains("$AOC_INPUT_DIR /nowhere/day99.txt (no such file)"));
+        assert!(err.contains("$AOC_INPUT_DIR /nowhere/day99/input.txt (no such file)"));
+        assert!(err.contains("--input (not given, use \"-\" for stdin)"));
+        assert!(err.contains("day99/input.txt (no such file)"));
+    }
+
+    #[test]
+    fn test_raw_lines_keep_indentation() {
+        let lines = raw_lines("    [D]\n[N] [C]\n\n move 1 from 2 to 1\n");
+        assert_eq!(vec!["    [D]", "[N] [C]", " move 1 from 2 to 1"], lines);
+    }
+
+    #[test]
+    fn test_trimmed_lines() {
+        let lines = trimmed_lines("  vJrwpWtwJgWrhcsFMMfFFhFp  \r\n\njqHRNqRjqzjGDLGL\n");
+        assert_eq!(vec!["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGL"], lines);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = blocks("1000\n2000\n\n4000\n\n\n5000\n6000\n");
+        assert_eq!(
+            vec![
+                vec![(1, "1000"), (2, "2000")],
+                vec![(4, "4000")],
+                vec![(7, "5000"), (8, "6000")]
+            ],
+            blocks
+        );
+    }
+
+    #[test]
+    fn test_numbered_lines() {
+        let lines: Vec<_> = numbered_lines("[1,1,3]\n[1,1,5]\n\n[[1]]\n").collect();
+        assert_eq!(vec![(1, "[1,1,3]"), (2, "[1,1,5]"), (4, "[[1]]")], lines);
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            single_line("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap()
+        );
+        assert!(matches!(single_line("\n"), Err(InputError::Empty)));
+        assert!(matches!(
+            single_line("a\nb\n"),
+            Err(InputError::MultipleLines(2))
+        ));
+    }
+}
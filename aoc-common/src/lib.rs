@@ -0,0 +1,9 @@
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod search;
+mod solution;
+
+pub use solution::{Answer, Bitmap, Solution};
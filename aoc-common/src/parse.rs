@@ -0,0 +1,305 @@
+use std::{num::IntErrorKind, num::ParseIntError, str::FromStr};
+
+use crate::error::ParseError;
+
+// Reads one line from left to right. Anything that fails leaves an error
+// pointing at the column it got stuck on, so callers only need to add the
+// line number.
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<'a> {
+    line: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Cursor { line, position: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    // 1-based, like the columns in errors.
+    pub fn column(&self) -> usize {
+        self.position + 1
+    }
+
+    // Trailing whitespace doesn't count as anything left to read.
+    pub fn at_end(&self) -> bool {
+        self.rest().trim_end().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    // An error at the cursor, showing the word or symbol found there.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.line, self.token(), expected)
+    }
+
+    fn token(&self) -> &'a str {
+        let rest = self.rest();
+        let len = match rest.chars().next() {
+            None => 0,
+            Some(c) if c.is_alphanumeric() => rest
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(rest.len()),
+            Some(c) => c.len_utf8(),
+        };
+        &rest[..len]
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.rest()[..len];
+        self.position += len;
+        taken
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.advance(rest.len() - rest.trim_start().len());
+    }
+
+    // Reads `literal` if the line carries on with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.advance(literal.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("\"{}\"", literal)))
+        }
+    }
+
+    // Reads whichever of `keywords` comes next, as long as it isn't just the
+    // start of a longer word.
+    pub fn keyword<'k>(&mut self, keywords: &[&'k str]) -> Result<&'k str, ParseError> {
+        for keyword in keywords {
+            let rest = self.rest();
+            let whole = rest.starts_with(keyword)
+                && !(keyword.ends_with(char::is_alphanumeric)
+                    && rest[keyword.len()..].starts_with(char::is_alphanumeric));
+            if whole {
+                self.advance(keyword.len());
+                return Ok(keyword);
+            }
+        }
+
+        let expected: Vec<String> = keywords.iter().map(|k| format!("\"{}\"", k)).collect();
+        Err(self.error(expected.join(" or ")))
+    }
+
+    // A run of digits with an optional minus sign.
+    pub fn integer<T>(&mut self, expected: &str) -> Result<T, ParseError>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let len = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+
+        let digits = &rest[..sign + len];
+        match digits.parse() {
+            Ok(value) => {
+                self.advance(digits.len());
+                Ok(value)
+            }
+            Err(e) if matches!(e.kind(), IntErrorKind::InvalidDigit) => Err(self.error(expected)),
+            Err(_) => Err(ParseError::at(
+                self.line,
+                digits,
+                format!("{} that isn't so large", expected),
+            )),
+        }
+    }
+
+    // Everything up to the next whitespace, which can't be empty.
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+        Ok(self.advance(len))
+    }
+
+    // A single character that `accept` is happy with.
+    pub fn char(
+        &mut self,
+        expected: &str,
+        accept: impl Fn(char) -> bool,
+    ) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if accept(c) => {
+                self.advance(c.len_utf8());
+                Ok(c)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    // One or more `item`s with `separator` between them.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    pub fn bracketed<T>(
+        &mut self,
+        open: &str,
+        close: &str,
+        inner: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        self.literal(open)?;
+        let value = inner(self)?;
+        self.literal(close)?;
+        Ok(value)
+    }
+
+    // Zero or more `item`s between brackets, like "[1,2,3]" or "[]".
+    pub fn list<T>(
+        &mut self,
+        open: &str,
+        separator: &str,
+        close: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.literal(open)?;
+        let mut items = vec![];
+        if self.eat(close) {
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            if !self.eat(separator) {
+                let expected = format!("\"{}\" or \"{}\"", separator, close);
+                return Err(self.error(expected));
+            }
+        }
+    }
+
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.at_end() {
+            self.position = self.line.len();
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+}
+
+// Runs `parser` over the whole of `line`, so anything it leaves behind other
+// than whitespace is an error.
+pub fn line<'a, T>(
+    line: &'a str,
+    parser: impl FnOnce(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let value = parser(&mut cursor)?;
+    cursor.end()?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence() {
+        let parsed = line("move 12 from 3 to -1  ", |c| {
+            c.literal("move ")?;
+            let quantity: u32 = c.integer("a number")?;
+            c.literal(" from ")?;
+            let from: i64 = c.integer("a number")?;
+            c.literal(" to ")?;
+            let to: i64 = c.integer("a number")?;
+            Ok((quantity, from, to))
+        });
+        assert_eq!(Ok((12, 3, -1)), parsed);
+
+        let err = line("move x", |c| {
+            c.literal("move ")?;
+            c.integer::<u32>("a number")
+        })
+        .unwrap_err();
+        assert_eq!(
+            (6, "x", "a number"),
+            (err.column, err.found.as_str(), err.expected.as_str())
+        );
+
+        let err = line("1 2", |c| c.integer::<u8>("a number")).unwrap_err();
+        assert_eq!(
+            (2, "the end of the line"),
+            (err.column, err.expected.as_str())
+        );
+
+        let err = line("300", |c| c.integer::<u8>("a number")).unwrap_err();
+        assert_eq!((1, "300"), (err.column, err.found.as_str()));
+    }
+
+    #[test]
+    fn test_keyword() {
+        let mut cursor = Cursor::new("addxy 1");
+        let err = cursor.keyword(&["noop", "addx"]).unwrap_err();
+        assert_eq!(
+            ("addxy", "\"noop\" or \"addx\""),
+            (err.found.as_str(), err.expected.as_str())
+        );
+
+        let mut cursor = Cursor::new("* old");
+        assert_eq!(Ok("*"), cursor.keyword(&["+", "*"]));
+        assert_eq!(" old", cursor.rest());
+    }
+
+    #[test]
+    fn test_lists() {
+        let items = line("79, 98, 1", |c| {
+            c.separated(", ", |c| c.integer::<u64>("a number"))
+        });
+        assert_eq!(Ok(vec![79, 98, 1]), items);
+
+        let list = |text| {
+            line(text, |c| {
+                c.list("[", ",", "]", |c| c.integer::<i64>("a number"))
+            })
+        };
+        assert_eq!(Ok(vec![]), list("[]"));
+        assert_eq!(Ok(vec![1, -2]), list("[1,-2]"));
+
+        let err = list("[1;2]").unwrap_err();
+        assert_eq!(
+            (3, ";", "\",\" or \"]\""),
+            (err.column, err.found.as_str(), err.expected.as_str())
+        );
+
+        let letter = line("[Z]", |c| {
+            c.bracketed("[", "]", |c| c.char("a letter", |c| c.is_ascii_uppercase()))
+        });
+        assert_eq!(Ok('Z'), letter);
+    }
+}
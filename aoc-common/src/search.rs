@@ -0,0 +1,243 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+// Walks back through the parents from `goal` to whichever start it was
+// reached from.
+fn path_to<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+// Fewest steps from any of `starts` to a node where `is_goal` holds, when
+// every step costs the same. The path includes both ends, so it's one longer
+// than the number of steps, and is None if no goal can be reached.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if parents.insert(start.clone(), None).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(path_to(&parents, node));
+        }
+
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+// How far every node reachable from `starts` is from the nearest of them, and
+// which way leads back.
+#[derive(Clone, Debug)]
+pub struct DistanceField<N> {
+    nodes: HashMap<N, (usize, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash> DistanceField<N> {
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.nodes.get(node).map(|(distance, _)| *distance)
+    }
+
+    // The path from `node` back to the nearest start, both ends included.
+    pub fn path_from(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut parent = self.nodes.get(node)?.1.clone();
+        while let Some(next) = parent {
+            path.push(next.clone());
+            parent = self.nodes[&next].1.clone();
+        }
+        Some(path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.nodes
+            .iter()
+            .map(|(node, (distance, _))| (node, *distance))
+    }
+}
+
+// Breadth first search that doesn't stop, giving the distance from `starts`
+// to everywhere reachable.
+pub fn distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> DistanceField<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes: HashMap<N, (usize, Option<N>)> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if nodes.insert(start.clone(), (0, None)).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = nodes.entry(next.clone()) {
+                entry.insert((distance + 1, Some(node.clone())));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    DistanceField { nodes }
+}
+
+// Cheapest path from any of `starts` to a goal, where `neighbours` gives each
+// next node along with the cost of stepping to it. Returns the path, both ends
+// included, and its total cost.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+// Dijkstra guided by `heuristic`, an estimate of the remaining cost that must
+// never be too high. On a grid with unit steps, `Point::manhattan` to the goal
+// is the usual choice.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut costs: HashMap<N, u64> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if costs.insert(start.clone(), 0).is_none() {
+            parents.insert(start.clone(), None);
+            queue.push(Reverse((heuristic(&start), 0, start)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if cost > costs[&node] {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some((path_to(&parents, node), cost));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|known| next_cost < *known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::Point, grid::Grid};
+
+    use super::*;
+
+    const MAZE: &str = "\
+S.#....
+.##.##.
+...#...
+.#...#E
+";
+
+    fn open(grid: &Grid<char>, p: Point) -> Vec<Point> {
+        grid.neighbours4(p).filter(|n| grid[*n] != '#').collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::from_chars(MAZE).unwrap();
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+
+        let path = bfs([start], |p| open(&grid, *p), |p| *p == end).unwrap();
+        assert_eq!(11, path.len() - 1);
+        assert_eq!((start, end), (path[0], path[path.len() - 1]));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let walled = MAZE.replace(".#...#E", ".#..##E");
+        let grid = Grid::from_chars(&walled).unwrap();
+        assert_eq!(None, bfs([start], |p| open(&grid, *p), |p| *p == end));
+    }
+
+    #[test]
+    fn test_distances() {
+        let grid = Grid::from_chars(MAZE).unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+        let field = distances([end], |p| open(&grid, *p));
+
+        assert_eq!(Some(11), field.distance(&Point::ORIGIN));
+        assert_eq!(None, field.distance(&Point::new(2, 0)));
+
+        let path = field.path_from(&Point::ORIGIN).unwrap();
+        assert_eq!(12, path.len());
+        assert_eq!((Point::ORIGIN, end), (path[0], path[11]));
+        assert!(field.path_from(&Point::new(2, 0)).is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar_agree() {
+        let grid = Grid::from_digits("1163\n1381\n2136\n3694\n").unwrap();
+        let end = Point::new(3, 3);
+        let neighbours = |p: &Point| {
+            grid.neighbours4(*p)
+                .map(|n| (n, grid[n] as u64))
+                .collect::<Vec<_>>()
+        };
+
+        let (path, cost) = dijkstra([Point::ORIGIN], neighbours, |p| *p == end).unwrap();
+        assert_eq!(17, cost);
+        assert_eq!(cost, path[1..].iter().map(|p| grid[*p] as u64).sum::<u64>());
+
+        let heuristic = |p: &Point| p.manhattan(end) as u64;
+        let (_, astar_cost) = astar([Point::ORIGIN], neighbours, heuristic, |p| *p == end).unwrap();
+        assert_eq!(cost, astar_cost);
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+
+use anyhow::Result;
+
+pub trait Solution {
+    type Input;
+
+    fn parse(text: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    Bitmap(Bitmap),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{}", i),
+            Answer::String(s) => write!(f, "{}", s),
+            Answer::Bitmap(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(i: i64) -> Self {
+        Answer::Integer(i.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(i: u64) -> Self {
+        Answer::Integer(i.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(i: usize) -> Self {
+        Answer::Integer(i as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_string())
+    }
+}
+
+impl From<Bitmap> for Answer {
+    fn from(b: Bitmap) -> Self {
+        Answer::Bitmap(b)
+    }
+}
+
+// A monochrome image, like the CRT that day 10 draws its answer on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bitmap {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, pixels: Vec<bool>) -> Self {
+        Bitmap { width, pixels }
+    }
+
+    pub fn blank(width: usize, height: usize) -> Self {
+        Bitmap::new(width, vec![false; width * height])
+    }
+
+    // Reads '#' as lit and anything else as dark, wrapping every `width` pixels.
+    pub fn from_pixels(width: usize, pixels: &str) -> Self {
+        Bitmap::new(width, pixels.chars().map(|c| c == '#').collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.len().div_ceil(self.width)
+    }
+
+    // Anything outside the image is dark.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && self.pixels.get(y * self.width + x) == Some(&true)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        assert!(
+            x < self.width,
+            "x = {} is off a bitmap {} wide",
+            x,
+            self.width
+        );
+        self.pixels[y * self.width + x] = lit;
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.pixels.chunks(self.width)
+    }
+}
+
+impl fmt::Display for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for lit in row {
+                write!(f, "{}", if *lit { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmap_display() {
+        let bitmap = Bitmap::from_pixels(4, "#..##..#");
+        assert_eq!(2, bitmap.height());
+        assert_eq!("#..#\n#..#", bitmap.to_string());
+
+        let mut bitmap = Bitmap::blank(3, 2);
+        bitmap.set(2, 1, true);
+        assert!(bitmap.get(2, 1) && !bitmap.get(1, 1) && !bitmap.get(3, 0));
+        assert_eq!("...\n..#", bitmap.to_string());
+    }
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::Integer(157), 157usize.into());
+        assert_eq!(Answer::String("CMZ".to_string()), "CMZ".into());
+    }
+}
@@ -0,0 +1,26 @@
+use std::hint::black_box;
+
+use aoc::days;
+use aoc_common::input;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Times parsing and each part separately against the bundled inputs.
+fn bench_days(c: &mut Criterion) {
+    for (number, day) in days::all() {
+        let text = input::resolve(number, None).unwrap().text;
+        let parsed = day.parse(&text).unwrap();
+
+        let mut group = c.benchmark_group(format!("day{}", number));
+        group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&text))));
+        group.bench_function("part1", |b| b.iter(|| day.part(black_box(&parsed), 1)));
+        group.bench_function("part2", |b| b.iter(|| day.part(black_box(&parsed), 2)));
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_days
+}
+criterion_main!(benches);
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::{input, Answer};
+use toml::{Table, Value};
+
+pub fn default_path() -> PathBuf {
+    input::workspace_dir().join("answers.toml")
+}
+
+// The accepted answers, keyed by day and part:
+//
+//     [day5]
+//     part1 = "CMZ"
+//     part2 = 12
+pub struct Answers {
+    table: Table,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = input::read(path)?;
+        Answers::parse(&text).with_context(|| format!("couldn't parse {}", path.display()))
+    }
+
+    fn parse(text: &str) -> Result<Self> {
+        Ok(Answers {
+            table: text.parse::<Table>()?,
+        })
+    }
+
+    pub fn expected(&self, day: u32, part: u32) -> Result<Option<String>> {
+        let value = self
+            .table
+            .get(&format!("day{}", day))
+            .and_then(|parts| parts.get(format!("part{}", part)));
+
+        match value {
+            None => Ok(None),
+            Some(Value::Integer(i)) => Ok(Some(i.to_string())),
+            Some(Value::String(s)) => Ok(Some(s.trim().to_string())),
+            Some(other) => Err(anyhow!(
+                "day {}, part {}: expected an integer or string answer, found {}",
+                day,
+                part,
+                other
+            )),
+        }
+    }
+}
+
+pub fn matches(answer: &Answer, expected: &str) -> bool {
+    answer.to_string().trim() == expected
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Bitmap;
+
+    use super::*;
+
+    #[test]
+    fn test_expected() {
+        let answers = Answers::parse(
+            r#"
+[day5]
+part1 = "CMZ"
+part2 = 12
+
+[day10]
+part2 = """
+#..#
+#..#
+"""
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(Some("CMZ".to_string()), answers.expected(5, 1).unwrap());
+        assert_eq!(Some("12".to_string()), answers.expected(5, 2).unwrap());
+        assert_eq!(None, answers.expected(6, 1).unwrap());
+
+        let bitmap = Answer::Bitmap(Bitmap::from_pixels(4, "#..##..#"));
+        assert!(matches(&bitmap, &answers.expected(10, 2).unwrap().unwrap()));
+    }
+}
@@ -0,0 +1,70 @@
+use std::any::Any;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solution};
+
+pub type Parsed = Box<dyn Any>;
+
+// One registered day, with its parsed input type erased so that every day can
+// be run, verified and timed the same way.
+#[derive(Clone, Copy)]
+pub struct Day {
+    parse: fn(&str) -> Result<Parsed>,
+    part: fn(&Parsed, u32) -> Result<Answer>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self
+    where
+        S::Input: 'static,
+    {
+        Day {
+            parse: |text| Ok(Box::new(S::parse(text)?)),
+            part: |input, part| {
+                let input = input
+                    .downcast_ref::<S::Input>()
+                    .ok_or(anyhow!("input was parsed by a different day"))?;
+                match part {
+                    1 => S::part1(input),
+                    2 => S::part2(input),
+                    _ => Err(anyhow!("there is no part {}", part)),
+                }
+            },
+        }
+    }
+
+    pub fn parse(&self, text: &str) -> Result<Parsed> {
+        (self.parse)(text)
+    }
+
+    pub fn part(&self, input: &Parsed, part: u32) -> Result<Answer> {
+        (self.part)(input, part)
+    }
+
+    pub fn solve(&self, text: &str, part: u32) -> Result<Answer> {
+        self.part(&self.parse(text)?, part)
+    }
+}
+
+pub fn day(day: u32) -> Option<Day> {
+    match day {
+        1 => Some(Day::of::<day1::Day1>()),
+        2 => Some(Day::of::<day2::Day2>()),
+        3 => Some(Day::of::<day3::Day3>()),
+        4 => Some(Day::of::<day4::Day4>()),
+        5 => Some(Day::of::<day5::Day5>()),
+        6 => Some(Day::of::<day6::Day6>()),
+        7 => Some(Day::of::<day7::Day7>()),
+        8 => Some(Day::of::<day8::Day8>()),
+        9 => Some(Day::of::<day9::Day9>()),
+        10 => Some(Day::of::<day10::Day10>()),
+        11 => Some(Day::of::<day11::Day11>()),
+        12 => Some(Day::of::<day12::Day12>()),
+        13 => Some(Day::of::<day13::Day13>()),
+        _ => None,
+    }
+}
+
+pub fn all() -> impl Iterator<Item = (u32, Day)> {
+    (1..=25).filter_map(|number| day(number).map(|d| (number, d)))
+}
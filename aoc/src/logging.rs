@@ -0,0 +1,19 @@
+use tracing::Level;
+
+// Solutions log their working through `tracing`. Normal runs only show
+// warnings, so stdout is just the answers, and logs always go to stderr.
+pub fn init(verbose: bool, trace: bool) {
+    let level = if trace {
+        Level::TRACE
+    } else if verbose {
+        Level::DEBUG
+    } else {
+        Level::WARN
+    };
+
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+}
@@ -0,0 +1,454 @@
+use std::{fs::File, path::PathBuf};
+
+use anyhow::{anyhow, Result};
+use aoc::{days, timing};
+use aoc_common::{error, input, parse, Answer, Solution};
+use clap::{Args, Parser, Subcommand};
+use day10::{
+    asm,
+    crt::Crt,
+    debugger::Debugger,
+    image::{self, Animation},
+    ocr,
+    vm::{Cpu, Observer},
+    Day10,
+};
+use day11::{
+    expression::Expression,
+    troop::{self, MonkeyTroop, Reduction, SimulationConfig},
+    worry::{BigUint, Worry},
+    Day11,
+};
+use day12::{render, Day12};
+
+use answers::Answers;
+
+mod answers;
+mod logging;
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solutions are doing to stderr
+    #[arg(long, short, global = true)]
+    verbose: bool,
+    /// Log every step the solutions take, implies --verbose
+    #[arg(long, global = true)]
+    trace: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one part of one day's solution
+    Run {
+        day: u32,
+        part: u32,
+        /// Path to the puzzle input, or "-" for stdin. Falls back to
+        /// $AOC_INPUT_DIR, then the repository's dayN/input.txt
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Rerun every solution and check it against the recorded answers
+    Verify {
+        /// Answers file, defaults to answers.toml in the repository root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Draw day 12's shortest route on its heightmap
+    Route {
+        /// 1 for the route from S, 2 for the best route from any lowest square
+        #[arg(default_value_t = 1)]
+        part: u32,
+        /// Path to the heightmap, defaults like `run`
+        #[arg(long)]
+        input: Option<String>,
+        /// Also write an SVG of the map coloured by height to this file
+        #[arg(long)]
+        svg: Option<PathBuf>,
+    },
+    /// Draw day 10's CRT, and save it as images
+    Screen {
+        /// Path to the program, defaults like `run`
+        #[arg(long)]
+        input: Option<String>,
+        /// Write the screen as a plain PBM to this file
+        #[arg(long)]
+        pbm: Option<PathBuf>,
+        /// Write the screen as a PNG to this file
+        #[arg(long)]
+        png: Option<PathBuf>,
+        /// Write an animated GIF of the beam drawing the screen, a frame per
+        /// cycle, to this file
+        #[arg(long)]
+        gif: Option<PathBuf>,
+        /// Size in image pixels of each screen pixel
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+    },
+    /// Assemble a day 10 program with labels and comments into the plain format
+    Asm {
+        /// Path to the source
+        source: PathBuf,
+        /// Write the program here instead of to stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// List a day 10 program with each instruction's pointer and cycles
+    Disasm {
+        /// Path to the program, defaults like `run`
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Step through a day 10 program, plain or assembly, interactively
+    Debug {
+        /// Path to the program, defaults like `run`
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Simulate day 11's monkeys for any number of rounds
+    Monkeys(MonkeyOptions),
+    /// Create a new day from the template and register it with the runner
+    New { day: u32 },
+    /// Print how long each day takes to parse and solve its input
+    Bench {
+        /// Only time this day
+        day: Option<u32>,
+        /// Report the best of this many runs
+        #[arg(long, default_value_t = 5)]
+        runs: u32,
+    },
+}
+
+#[derive(Args)]
+struct MonkeyOptions {
+    /// Defaults to the part's own number of rounds. Huge counts are
+    /// extrapolated when worry levels are reduced, as in part 2
+    rounds: Option<u64>,
+    /// Which part's relief and worry reduction rules to start from
+    #[arg(long, default_value_t = 2)]
+    part: u32,
+    /// Replace the part's relief rule, like "old / 3" or "old"
+    #[arg(long)]
+    relief: Option<String>,
+    /// Replace the part's worry reduction
+    #[arg(long, value_parser = ["none", "product", "lcm"])]
+    reduction: Option<String>,
+    /// Keep worry levels as big integers, so they can't overflow without
+    /// reduction
+    #[arg(long)]
+    big: bool,
+    /// How many of the busiest monkeys make up the monkey business
+    #[arg(long, default_value_t = 2)]
+    top: usize,
+    /// Path to the notes, defaults like `run`
+    #[arg(long)]
+    input: Option<String>,
+    /// Write every round's transfers and inspection counts to this file, as
+    /// JSON if it ends in .json and CSV otherwise
+    #[arg(long, conflicts_with = "big")]
+    history: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    logging::init(cli.verbose, cli.trace);
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let solution = days::day(day).ok_or(anyhow!("no solution for day {}", day))?;
+            let input = input::resolve(day, input.as_deref())?;
+
+            let answer = solution
+                .solve(&input.text, part)
+                .map_err(|e| error::in_file(e, &input.source))?;
+
+            match answer {
+                Answer::Bitmap(bitmap) => {
+                    println!("Day {}, part {} result:\n{}", day, part, bitmap)
+                }
+                answer => println!("Day {}, part {} result: {}", day, part, answer),
+            }
+        }
+        Command::Verify { answers } => {
+            verify(&answers.unwrap_or_else(answers::default_path))?;
+        }
+        Command::Route { part, input, svg } => route(part, input.as_deref(), svg)?,
+        Command::Screen {
+            input,
+            pbm,
+            png,
+            gif,
+            scale,
+        } => screen(input.as_deref(), pbm, png, gif, scale as usize)?,
+        Command::Asm { source, output } => {
+            let text = input::read(&source)?;
+            let program =
+                asm::assemble(&text).map_err(|e| e.in_file(source.display().to_string()))?;
+            match output {
+                Some(path) => std::fs::write(path, program.to_text())?,
+                None => print!("{}", program.to_text()),
+            }
+        }
+        Command::Disasm { input } => {
+            let input = input::resolve(10, input.as_deref())?;
+            let program =
+                Day10::parse(&input.text).map_err(|e| error::in_file(e, &input.source))?;
+            print!("{}", asm::disassemble(&program));
+        }
+        Command::Debug { input } => {
+            let input = input::resolve(10, input.as_deref())?;
+            let program =
+                asm::assemble(&input.text).map_err(|e| e.in_file(input.source.clone()))?;
+            Debugger::new(program).repl(std::io::stdin().lock(), std::io::stdout())?;
+        }
+        Command::Monkeys(options) => monkeys(options)?,
+        Command::New { day } => {
+            scaffold::new_day(&input::workspace_dir(), day)?;
+            println!("Created day{}, fill in sample.txt and input.txt", day);
+        }
+        Command::Bench { day, runs } => bench(day, runs)?,
+    }
+
+    Ok(())
+}
+
+fn verify(path: &std::path::Path) -> Result<()> {
+    let answers = Answers::load(path)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!("{:>3}  {:>4}  {:<7}  details", "day", "part", "status");
+    for (day, solution) in days::all() {
+        let input = input::resolve(day, None);
+
+        for part in [1, 2] {
+            let expected = answers.expected(day, part)?;
+            let result = match &input {
+                // A day that's just been created with `new` has nothing to run.
+                Ok(input) if input.text.trim().is_empty() => {
+                    missing += 1;
+                    println!("{:>3}  {:>4}  {:<7}  no input yet", day, part, "missing");
+                    continue;
+                }
+                Ok(input) => solution
+                    .solve(&input.text, part)
+                    .map_err(|e| error::in_file(e, &input.source)),
+                Err(e) => Err(anyhow!("{}", e)),
+            };
+
+            let (status, details) = match (result, expected) {
+                (Ok(answer), Some(expected)) if answers::matches(&answer, &expected) => {
+                    passed += 1;
+                    ("pass", summary(&answer.to_string()))
+                }
+                (Ok(answer), Some(expected)) => {
+                    failed += 1;
+                    let details = format!(
+                        "got {}, expected {}",
+                        summary(&answer.to_string()),
+                        summary(&expected)
+                    );
+                    ("FAIL", details)
+                }
+                (Ok(answer), None) => {
+                    missing += 1;
+                    let details = format!("got {}, not recorded", summary(&answer.to_string()));
+                    ("missing", details)
+                }
+                (Err(e), None) => {
+                    missing += 1;
+                    let details = format!("{}, not recorded", summary(&e.to_string()));
+                    ("missing", details)
+                }
+                (Err(e), Some(_)) => {
+                    failed += 1;
+                    ("ERROR", summary(&e.to_string()))
+                }
+            };
+
+            println!("{:>3}  {:>4}  {:<7}  {}", day, part, status, details);
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        return Err(anyhow!("{} answers didn't verify", failed));
+    }
+    Ok(())
+}
+
+fn route(part: u32, input: Option<&str>, svg: Option<PathBuf>) -> Result<()> {
+    let input = input::resolve(12, input)?;
+    let heightmap = Day12::parse(&input.text).map_err(|e| error::in_file(e, &input.source))?;
+
+    let route = match part {
+        1 => day12::part1::route(&heightmap)?,
+        2 => day12::part2::route(&heightmap)?,
+        _ => return Err(anyhow!("day 12 has no part {}", part)),
+    };
+
+    println!("{} steps:", route.len() - 1);
+    println!("{}", render::arrows(&heightmap, &route));
+
+    if let Some(path) = svg {
+        std::fs::write(&path, render::svg(&heightmap, &route))?;
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn screen(
+    input: Option<&str>,
+    pbm: Option<PathBuf>,
+    png: Option<PathBuf>,
+    gif: Option<PathBuf>,
+    scale: usize,
+) -> Result<()> {
+    let input = input::resolve(10, input)?;
+    let program = Day10::parse(&input.text).map_err(|e| error::in_file(e, &input.source))?;
+
+    let mut crt = Crt::new(40, 6);
+    let mut animation = Animation::new(40, 6);
+    let mut observers: Vec<&mut dyn Observer> = vec![&mut crt];
+    if gif.is_some() {
+        observers.push(&mut animation);
+    }
+    Cpu::new(program).run_observed(&mut observers)?;
+
+    println!("{}", crt);
+    match ocr::read(crt.screen()) {
+        Ok(letters) => println!("Reads as {}", letters),
+        Err(e) => println!("Couldn't read it: {}", e),
+    }
+
+    if let Some(path) = pbm {
+        std::fs::write(&path, image::pbm(crt.screen()))?;
+        println!("Wrote {}", path.display());
+    }
+    if let Some(path) = png {
+        image::write_png(crt.screen(), scale, File::create(&path)?)?;
+        println!("Wrote {}", path.display());
+    }
+    if let Some(path) = gif {
+        animation.write_gif(scale, 5, File::create(&path)?)?;
+        println!("Wrote {} frames to {}", animation.frames(), path.display());
+    }
+
+    Ok(())
+}
+
+fn monkeys(options: MonkeyOptions) -> Result<()> {
+    let input = input::resolve(11, options.input.as_deref())?;
+    let specs = Day11::parse(&input.text).map_err(|e| error::in_file(e, &input.source))?;
+
+    let mut config = match options.part {
+        1 => SimulationConfig::part1(),
+        2 => SimulationConfig::part2(),
+        part => return Err(anyhow!("day 11 has no part {}", part)),
+    };
+    if let Some(relief) = &options.relief {
+        config.relief = parse::line(relief, Expression::parse)?;
+    }
+    config.reduction = match options.reduction.as_deref() {
+        Some("none") => Reduction::None,
+        Some("product") => Reduction::Product,
+        Some("lcm") => Reduction::Lcm,
+        _ => config.reduction,
+    };
+    let rounds = options.rounds.unwrap_or(config.rounds as u64);
+
+    let inspections = match options.history {
+        Some(path) => {
+            config.rounds = usize::try_from(rounds)?;
+            let mut troop: MonkeyTroop = MonkeyTroop::new(&specs);
+            let history = troop.run_recorded(&config)?;
+            let text = match path.extension().and_then(|e| e.to_str()) {
+                Some("json") => history.to_json(),
+                _ => history.to_csv(),
+            };
+            std::fs::write(&path, text)?;
+            eprintln!("Wrote {}", path.display());
+            troop.inspections()
+        }
+        None if options.big => simulate(MonkeyTroop::<BigUint>::new(&specs), config, rounds)?,
+        None => simulate(MonkeyTroop::<u64>::new(&specs), config, rounds)?,
+    };
+
+    for (monkey, count) in &inspections {
+        println!("Monkey {} inspected items {} times", monkey, count);
+    }
+    println!(
+        "Monkey business after {} rounds: {}",
+        rounds,
+        troop::monkey_business(&inspections, options.top)
+    );
+
+    Ok(())
+}
+
+// Extrapolates when the config allows it, and simulates every round otherwise.
+fn simulate<W: Worry>(
+    mut troop: MonkeyTroop<W>,
+    mut config: SimulationConfig,
+    rounds: u64,
+) -> Result<Vec<(u64, u64)>> {
+    if let Some(inspections) = troop.extrapolate(&config, rounds)? {
+        return Ok(inspections);
+    }
+
+    config.rounds = usize::try_from(rounds)?;
+    troop.run(&config)?;
+    Ok(troop.inspections())
+}
+
+fn bench(only: Option<u32>, runs: u32) -> Result<()> {
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "day", "parse", "part1", "part2", "total"
+    );
+    let mut failed = 0;
+
+    for (day, solution) in days::all().filter(|(day, _)| only.is_none_or(|only| only == *day)) {
+        let timings = input::resolve(day, None)
+            .map_err(|e| anyhow!("{}", e))
+            .and_then(|input| {
+                timing::time(&solution, &input.text, runs)
+                    .map_err(|e| error::in_file(e, &input.source))
+            });
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(e) => {
+                failed += 1;
+                println!("{:>3}  ERROR  {}", day, summary(&e.to_string()));
+                continue;
+            }
+        };
+        println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+            day,
+            format!("{:.1?}", timings.parse),
+            format!("{:.1?}", timings.part1),
+            format!("{:.1?}", timings.part2),
+            format!("{:.1?}", timings.total()),
+        );
+    }
+
+    if failed > 0 {
+        return Err(anyhow!("couldn't time {} of the days", failed));
+    }
+    Ok(())
+}
+
+// Keeps multi-line answers, like day 10's CRT, to a single table row
+fn summary(s: &str) -> String {
+    let s = s.trim();
+    match s.lines().count() {
+        0 | 1 => s.to_string(),
+        n => format!("{} (+{} lines)", s.lines().next().unwrap(), n - 1),
+    }
+}
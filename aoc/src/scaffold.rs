@@ -0,0 +1,134 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const PART_RS: &str = include_str!("../templates/part.rs.tmpl");
+
+// Creates dayN from the templates and registers it with the workspace and the
+// runner. Never touches an existing day.
+pub fn new_day(root: &Path, day: u32) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        return Err(anyhow!("day must be between 1 and 25, got {}", day));
+    }
+
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(anyhow!(
+            "{} already exists, refusing to overwrite it",
+            dir.display()
+        ));
+    }
+
+    let render = |template: &str| template.replace("{day}", &day.to_string());
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TOML))?;
+    fs::write(dir.join("src").join("lib.rs"), render(LIB_RS))?;
+    fs::write(dir.join("src").join("part1.rs"), render(PART_RS))?;
+    fs::write(dir.join("src").join("part2.rs"), render(PART_RS))?;
+    fs::write(dir.join("input.txt"), "")?;
+    fs::write(dir.join("sample.txt"), "")?;
+
+    insert_before(
+        &root.join("Cargo.toml"),
+        |line| line == "]",
+        &format!("    \"{}\",", name),
+    )?;
+    insert_after_last(
+        &root.join("aoc").join("Cargo.toml"),
+        |line| line.starts_with("day"),
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+    )?;
+    insert_before(
+        &root.join("aoc").join("src").join("days.rs"),
+        |line| line.trim() == "_ => None,",
+        &format!(
+            "        {} => Some(Day::of::<{}::Day{}>()),",
+            day, name, day
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn insert_before(path: &Path, at: impl Fn(&str) -> bool, new_line: &str) -> Result<()> {
+    let mut lines = read_lines(path)?;
+    let index = lines.iter().position(|line| at(line)).ok_or(anyhow!(
+        "couldn't find where to register the day in {}",
+        path.display()
+    ))?;
+    lines.insert(index, new_line.to_string());
+    write_lines(path, &lines)
+}
+
+fn insert_after_last(path: &Path, at: impl Fn(&str) -> bool, new_line: &str) -> Result<()> {
+    let mut lines = read_lines(path)?;
+    let index = lines.iter().rposition(|line| at(line)).ok_or(anyhow!(
+        "couldn't find where to register the day in {}",
+        path.display()
+    ))?;
+    lines.insert(index + 1, new_line.to_string());
+    write_lines(path, &lines)
+}
+
+fn read_lines(path: &Path) -> Result<Vec<String>> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))?;
+    Ok(text.lines().map(|line| line.to_string()).collect())
+}
+
+fn write_lines(path: &Path, lines: &[String]) -> Result<()> {
+    fs::write(path, lines.join("\n") + "\n")
+        .with_context(|| format!("couldn't write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day3\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday3 = { path = \"../day3\" }\ntoml = \"0.8\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "    match day {\n        3 => Some(Day::of::<day3::Day3>()),\n        _ => None,\n    }\n",
+        )
+        .unwrap();
+
+        new_day(&root, 14).unwrap();
+
+        let lib = fs::read_to_string(root.join("day14/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day14 {"));
+        assert!(root.join("day14/sample.txt").is_file());
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day3\",\n    \"day14\",\n]\n",
+            fs::read_to_string(root.join("Cargo.toml")).unwrap()
+        );
+        assert_eq!(
+            "[dependencies]\nday3 = { path = \"../day3\" }\nday14 = { path = \"../day14\" }\ntoml = \"0.8\"\n",
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap()
+        );
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("        14 => Some(Day::of::<day14::Day14>()),\n        _ => None,"));
+
+        let err = new_day(&root, 14).unwrap_err().to_string();
+        assert!(err.ends_with("day14 already exists, refusing to overwrite it"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
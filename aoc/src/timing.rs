@@ -0,0 +1,43 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::days::Day;
+
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+// Best of `runs` for each stage, timed separately so slow parsing can be told
+// apart from a slow solution.
+pub fn time(day: &Day, text: &str, runs: u32) -> Result<Timings> {
+    let mut timings = Timings {
+        parse: Duration::MAX,
+        part1: Duration::MAX,
+        part2: Duration::MAX,
+    };
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let input = day.parse(text)?;
+        timings.parse = timings.parse.min(start.elapsed());
+
+        let start = Instant::now();
+        day.part(&input, 1)?;
+        timings.part1 = timings.part1.min(start.elapsed());
+
+        let start = Instant::now();
+        day.part(&input, 2)?;
+        timings.part2 = timings.part2.min(start.elapsed());
+    }
+
+    Ok(timings)
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    // The calories carried by each elf, one group per blank-line separated block
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        let elves = input::blocks(text)
+            .into_iter()
+            .map(|block| {
+                block
+                    .into_iter()
+                    .map(|(number, line)| {
+                        let calories = line.trim();
+                        calories.parse::<i64>().map_err(|_| {
+                            ParseError::at(line, calories, "a calorie count").at_line(number, line)
+                        })
+                    })
+                    .collect::<Result<Vec<i64>, ParseError>>()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(elves).into())
+    }
+
+    fn part2(elves: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(elves).into())
+    }
+}
+
+// The combined calories of the `n` elves carrying the most.
+pub fn top_calories(elves: &[Vec<i64>], n: usize) -> i64 {
+    let mut totals: Vec<i64> = elves.iter().map(|elf| elf.iter().sum()).collect();
+    totals.sort();
+    totals.iter().rev().take(n).sum()
+}
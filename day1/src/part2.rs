@@ -0,0 +1,22 @@
+use crate::top_calories;
+
+pub fn solve(elves: &[Vec<i64>]) -> i64 {
+    top_calories(elves, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day1;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 45000;
+        let input = Day1::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+
+use aoc_common::{error::ParseError, input};
+
+use crate::Instruction;
+
+// An assembled program. Labels don't change the instructions, they just name
+// places in it for the debugger and the listing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+    // The instruction pointer of the instruction after each label.
+    pub labels: BTreeMap<String, usize>,
+}
+
+impl Program {
+    // The plain format the puzzle input uses, one instruction per line.
+    pub fn to_text(&self) -> String {
+        self.instructions
+            .iter()
+            .map(|instruction| format!("{}\n", instruction))
+            .collect()
+    }
+}
+
+// Reads instructions like the puzzle input's, plus `;` comments and `name:`
+// labels, either on their own line or in front of an instruction:
+//
+//     start:  addx 15   ; sprite to the right
+//             noop
+pub fn assemble(source: &str) -> Result<Program, ParseError> {
+    let mut program = Program::default();
+
+    for (number, line) in input::numbered_lines(source) {
+        let mut code = line.split(';').next().unwrap();
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                let found = if label.is_empty() {
+                    &code[code.len() - rest.len() - 1..]
+                } else {
+                    label
+                };
+                return Err(ParseError::at(line, found, "a label").at_line(number, line));
+            }
+            if program.labels.contains_key(label) {
+                return Err(
+                    ParseError::at(line, label, "a label that isn't taken").at_line(number, line)
+                );
+            }
+            program
+                .labels
+                .insert(label.to_string(), program.instructions.len());
+            code = rest;
+        }
+
+        if code.trim().is_empty() {
+            continue;
+        }
+        let offset = code.as_ptr() as usize - line.as_ptr() as usize;
+        let instruction = code.parse().map_err(|mut err: ParseError| {
+            err.column += offset;
+            err.at_line(number, line)
+        })?;
+        program.instructions.push(instruction);
+    }
+
+    Ok(program)
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// A listing that assembles back to the same program, with each instruction's
+// pointer and the cycles it runs during in a comment.
+pub fn disassemble(instructions: &[Instruction]) -> String {
+    let mut out = String::new();
+    let mut cycle = 1;
+    for (ip, instruction) in instructions.iter().enumerate() {
+        let cycles = instruction.opcode.info().cycles as u64;
+        let during = match cycles {
+            1 => format!("cycle {}", cycle),
+            _ => format!("cycles {}-{}", cycle, cycle + cycles - 1),
+        };
+        out.push_str(&format!(
+            "{:<12}; {}: {}\n",
+            instruction.to_string(),
+            ip,
+            during
+        ));
+        cycle += cycles;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assemble() {
+        let program = assemble(
+            "; draws a bar\n\
+             start: addx 15 ; move the sprite\n\
+             \n\
+             loop:\n\
+             \tnoop\n\
+             \taddx -11\n",
+        )
+        .unwrap();
+
+        assert_eq!("addx 15\nnoop\naddx -11\n", program.to_text());
+        assert_eq!(Some(&0), program.labels.get("start"));
+        assert_eq!(Some(&1), program.labels.get("loop"));
+
+        let listing = disassemble(&program.instructions);
+        assert_eq!(
+            "addx 15     ; 0: cycles 1-2\nnoop        ; 1: cycle 3\naddx -11    ; 2: cycles 4-5\n",
+            listing
+        );
+        assert_eq!(
+            program.instructions,
+            assemble(&listing).unwrap().instructions
+        );
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let err = assemble("noop\n  addx one").unwrap_err();
+        assert_eq!((2, 8, "one"), (err.line, err.column, err.found.as_str()));
+
+        let err = assemble("a: noop\na: noop").unwrap_err();
+        assert_eq!(
+            (2, 1, "a label that isn't taken"),
+            (err.line, err.column, err.expected.as_str())
+        );
+
+        let err = assemble("2x: noop").unwrap_err();
+        assert_eq!((1, "a label"), (err.column, err.expected.as_str()));
+    }
+}
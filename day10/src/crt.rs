@@ -0,0 +1,91 @@
+use std::fmt;
+
+use aoc_common::Bitmap;
+
+use crate::vm::{Observer, Register, Registers};
+
+// A screen the CPU draws on, one pixel per cycle in reading order. A pixel is
+// lit when the three pixel wide sprite centred on X covers its column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crt {
+    screen: Bitmap,
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize) -> Self {
+        Crt {
+            screen: Bitmap::blank(width, height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.screen.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.screen.height()
+    }
+
+    // What's been drawn so far.
+    pub fn screen(&self) -> &Bitmap {
+        &self.screen
+    }
+
+    // Which pixel is drawn during `cycle`, if it's on the screen at all.
+    pub fn beam(&self, cycle: u64) -> Option<(usize, usize)> {
+        let position = usize::try_from(cycle.checked_sub(1)?).ok()?;
+        (position < self.width() * self.height())
+            .then(|| (position % self.width(), position / self.width()))
+    }
+}
+
+// Whether the three pixel wide sprite centred on `sprite` covers column `x`.
+// X can be anything, so this can't overflow however far off screen it is.
+pub fn sprite_covers(sprite: i64, x: usize) -> bool {
+    i64::try_from(x).is_ok_and(|x| x.abs_diff(sprite) <= 1)
+}
+
+impl Observer for Crt {
+    fn during(&mut self, cycle: u64, registers: &Registers) {
+        if let Some((x, y)) = self.beam(cycle) {
+            let lit = sprite_covers(registers[Register::X], x);
+            self.screen.set(x, y, lit);
+        }
+    }
+}
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.screen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beam() {
+        let crt = Crt::new(40, 6);
+        assert_eq!(None, crt.beam(0));
+        assert_eq!(Some((0, 0)), crt.beam(1));
+        assert_eq!(Some((0, 1)), crt.beam(41));
+        assert_eq!(Some((39, 5)), crt.beam(240));
+        assert_eq!(None, crt.beam(241));
+    }
+
+    #[test]
+    fn test_sprite_covers() {
+        assert!(sprite_covers(-1, 0));
+        assert!(sprite_covers(4, 5));
+        assert!(!sprite_covers(4, 6));
+        assert!(!sprite_covers(i64::MIN, 0));
+        assert!(!sprite_covers(i64::MAX, 39));
+
+        let mut crt = Crt::new(2, 1);
+        let mut registers = Registers::default();
+        registers[Register::X] = i64::MIN + 1;
+        crt.during(1, &registers);
+        assert!(!crt.screen().get(0, 0));
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{BufRead, Write},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{asm::Program, crt::Crt, vm::Cpu};
+
+const HELP: &str = "\
+step [N]            run N cycles, 1 if not given
+continue            run until a breakpoint or the end of the program
+break cycle N       stop before cycle N
+break ip N          stop before instruction N starts
+break LABEL         stop before the instruction after LABEL starts
+delete N            remove breakpoint N
+breakpoints         list the breakpoints
+registers           show the cycle, instruction pointer and registers
+list                show the instructions around the current one
+screen              show what's been drawn so far, @ marks the beam
+preview on|off      show the screen every time the program stops
+quit";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    Cycle(u64),
+    Instruction(usize),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Cycle(cycle) => write!(f, "cycle {}", cycle),
+            Breakpoint::Instruction(ip) => write!(f, "ip {}", ip),
+        }
+    }
+}
+
+// Runs a program a cycle at a time under the control of typed commands,
+// drawing on a CRT as it goes.
+pub struct Debugger {
+    cpu: Cpu,
+    crt: Crt,
+    labels: BTreeMap<String, usize>,
+    // Numbered from 1, and deleted ones leave a gap.
+    breakpoints: BTreeMap<usize, Breakpoint>,
+    next_breakpoint: usize,
+    preview: bool,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Self {
+        Debugger {
+            cpu: Cpu::new(program.instructions),
+            crt: Crt::new(40, 6),
+            labels: program.labels,
+            breakpoints: BTreeMap::new(),
+            next_breakpoint: 1,
+            preview: true,
+        }
+    }
+
+    pub fn cpu(&self) -> &Cpu {
+        &self.cpu
+    }
+
+    pub fn crt(&self) -> &Crt {
+        &self.crt
+    }
+
+    // Reads commands until `quit` or the end of the input. A bad command is
+    // reported and the session carries on.
+    pub fn repl(&mut self, input: impl BufRead, mut output: impl Write) -> Result<()> {
+        writeln!(output, "{}", self.status())?;
+        write!(output, "(day10) ")?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+            if matches!(line.trim(), "quit" | "q") {
+                break;
+            }
+            match self.execute(&line) {
+                Ok(text) if text.is_empty() => {}
+                Ok(text) => writeln!(output, "{}", text)?,
+                Err(e) => writeln!(output, "error: {}", e)?,
+            }
+            write!(output, "(day10) ")?;
+            output.flush()?;
+        }
+        writeln!(output)?;
+        Ok(())
+    }
+
+    // Runs one command, returning what it has to say.
+    pub fn execute(&mut self, line: &str) -> Result<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => Ok(String::new()),
+            ["step" | "s"] => self.step(1),
+            ["step" | "s", count] => self.step(number(count)?),
+            ["continue" | "c"] => self.resume(),
+            ["break" | "b", "cycle", cycle] => {
+                self.add_breakpoint(Breakpoint::Cycle(number(cycle)?))
+            }
+            ["break" | "b", "ip", ip] => self.add_breakpoint(Breakpoint::Instruction(number(ip)?)),
+            ["break" | "b", label] => {
+                let ip = *self
+                    .labels
+                    .get(*label)
+                    .ok_or_else(|| anyhow!("no label called {}", label))?;
+                self.add_breakpoint(Breakpoint::Instruction(ip))
+            }
+            ["delete" | "d", id] => {
+                let id = number(id)?;
+                self.breakpoints
+                    .remove(&id)
+                    .ok_or_else(|| anyhow!("no breakpoint {}", id))?;
+                Ok(format!("Deleted breakpoint {}", id))
+            }
+            ["breakpoints"] if self.breakpoints.is_empty() => Ok("No breakpoints".to_string()),
+            ["breakpoints"] => Ok(self
+                .breakpoints
+                .iter()
+                .map(|(id, breakpoint)| format!("{}: {}", id, breakpoint))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            ["registers" | "r"] => Ok(self.status()),
+            ["list" | "l"] => Ok(self.list()),
+            ["screen"] => Ok(self.screen()),
+            ["preview", "on"] => {
+                self.preview = true;
+                Ok(String::new())
+            }
+            ["preview", "off"] => {
+                self.preview = false;
+                Ok(String::new())
+            }
+            ["help" | "h"] => Ok(HELP.to_string()),
+            _ => Err(anyhow!("unknown command \"{}\", try help", line.trim())),
+        }
+    }
+
+    fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> Result<String> {
+        let id = self.next_breakpoint;
+        self.next_breakpoint += 1;
+        self.breakpoints.insert(id, breakpoint);
+        Ok(format!("Breakpoint {} at {}", id, breakpoint))
+    }
+
+    // An overflow stops the program where it happened, and every later step
+    // reports it again.
+    fn step(&mut self, count: u64) -> Result<String> {
+        for _ in 0..count {
+            if !self.cpu.step_observed(&mut [&mut self.crt])? {
+                break;
+            }
+        }
+        Ok(self.stopped(None))
+    }
+
+    // Always runs at least a cycle, so continuing from a breakpoint doesn't
+    // stop straight away on the same one.
+    fn resume(&mut self) -> Result<String> {
+        while self.cpu.step_observed(&mut [&mut self.crt])? {
+            if let Some(id) = self.hit() {
+                return Ok(self.stopped(Some(id)));
+            }
+        }
+        Ok(self.stopped(None))
+    }
+
+    fn hit(&self) -> Option<usize> {
+        self.breakpoints
+            .iter()
+            .find(|(_, breakpoint)| match breakpoint {
+                Breakpoint::Cycle(cycle) => self.cpu.cycle == *cycle,
+                Breakpoint::Instruction(ip) => {
+                    self.cpu.instruction_pointer == *ip && self.cpu.starting_instruction()
+                }
+            })
+            .map(|(id, _)| *id)
+    }
+
+    fn stopped(&self, breakpoint: Option<usize>) -> String {
+        let mut out = String::new();
+        if let Some(id) = breakpoint {
+            out.push_str(&format!("Breakpoint {}, ", id));
+        }
+        out.push_str(&self.status());
+        if self.preview {
+            out.push('\n');
+            out.push_str(&self.screen());
+        }
+        out
+    }
+
+    fn status(&self) -> String {
+        let cpu = &self.cpu;
+        match cpu.current_instruction() {
+            Some(instruction) => format!(
+                "cycle {}, ip {}: {} ({})",
+                cpu.cycle, cpu.instruction_pointer, instruction, cpu.registers
+            ),
+            None => format!("cycle {}, halted ({})", cpu.cycle, cpu.registers),
+        }
+    }
+
+    fn list(&self) -> String {
+        let ip = self.cpu.instruction_pointer;
+        let program = self.cpu.program();
+        let names: BTreeMap<usize, &str> = self
+            .labels
+            .iter()
+            .map(|(name, ip)| (*ip, name.as_str()))
+            .collect();
+
+        let mut lines = vec![];
+        let start = ip.saturating_sub(3);
+        for (i, instruction) in program.iter().enumerate().skip(start).take(ip + 4 - start) {
+            if let Some(name) = names.get(&i) {
+                lines.push(format!("      {}:", name));
+            }
+            let marker = if i == ip { ">" } else { " " };
+            lines.push(format!("{} {:>3}  {}", marker, i, instruction));
+        }
+        if ip >= program.len() {
+            lines.push(format!(">{:>4}  (end)", ip));
+        }
+        lines.join("\n")
+    }
+
+    // Pixels the beam hasn't reached yet are left blank.
+    fn screen(&self) -> String {
+        let beam = self.crt.beam(self.cpu.cycle);
+        let drawn = (self.cpu.cycle - 1) as usize;
+        let mut rows = vec![];
+        for (y, row) in self.crt.screen().rows().enumerate() {
+            let row: String = row
+                .iter()
+                .enumerate()
+                .map(|(x, lit)| match y * self.crt.width() + x {
+                    _ if beam == Some((x, y)) => '@',
+                    i if i >= drawn => ' ',
+                    _ if *lit => '#',
+                    _ => '.',
+                })
+                .collect();
+            rows.push(row.trim_end().to_string());
+        }
+        rows.join("\n").trim_end().to_string()
+    }
+}
+
+fn number<T: std::str::FromStr>(text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| anyhow!("expected a number, found \"{}\"", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input::read;
+
+    use crate::asm::assemble;
+
+    use super::*;
+
+    fn debugger() -> Debugger {
+        let mut program = assemble(&read("sample.txt").unwrap()).unwrap();
+        program.labels.insert("third".to_string(), 2);
+        let mut debugger = Debugger::new(program);
+        debugger.execute("preview off").unwrap();
+        debugger
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = debugger();
+        assert_eq!(
+            "Breakpoint 1 at cycle 20",
+            debugger.execute("break cycle 20").unwrap()
+        );
+        assert_eq!("Breakpoint 2 at ip 2", debugger.execute("b third").unwrap());
+
+        assert_eq!(
+            "Breakpoint 2, cycle 5, ip 2: addx 6 (x=5 y=0 z=0 w=0)",
+            debugger.execute("continue").unwrap()
+        );
+        assert_eq!(
+            "Breakpoint 1, cycle 20, ip 10: addx -1 (x=21 y=0 z=0 w=0)",
+            debugger.execute("c").unwrap()
+        );
+        debugger.execute("delete 1").unwrap();
+        assert_eq!("2: ip 2", debugger.execute("breakpoints").unwrap());
+
+        assert!(debugger.execute("c").unwrap().contains("halted"));
+        assert_eq!(241, debugger.cpu().cycle);
+        assert!(debugger.execute("break nowhere").is_err());
+        assert!(debugger.execute("delete 1").is_err());
+    }
+
+    #[test]
+    fn test_step_and_screen() {
+        let mut debugger = debugger();
+        assert_eq!(
+            "cycle 4, ip 1: addx -11 (x=16 y=0 z=0 w=0)",
+            debugger.execute("step 3").unwrap()
+        );
+        assert_eq!("##.@", debugger.execute("screen").unwrap());
+        assert_eq!(
+            "    0  addx 15\n>   1  addx -11\n      third:\n    2  addx 6\n    3  addx -3\n    4  addx 5",
+            debugger.execute("list").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let program = assemble("addx 9223372036854775807\nnoop").unwrap();
+        let mut debugger = Debugger::new(program);
+        debugger.execute("step").unwrap();
+        let err = debugger.execute("continue").unwrap_err();
+        assert!(err.to_string().contains("overflows in cycle 2"));
+        assert!(debugger.execute("step").is_err());
+        assert_eq!(
+            "cycle 2, ip 0: addx 9223372036854775807 (x=1 y=0 z=0 w=0)",
+            debugger.execute("registers").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut output = vec![];
+        debugger()
+            .repl("step\nfrobnicate\nquit\nstep\n".as_bytes(), &mut output)
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("cycle 1, ip 0: addx 15"));
+        assert!(output.contains("cycle 2, ip 0: addx 15"));
+        assert!(output.contains("error: unknown command \"frobnicate\""));
+        assert!(!output.contains("cycle 3"));
+    }
+}
@@ -0,0 +1,203 @@
+use std::io::Write;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Bitmap;
+
+use crate::{
+    crt::{sprite_covers, Crt},
+    vm::{Observer, Register, Registers},
+};
+
+// Lit pixels are drawn as black ink on white, like the PBM convention.
+const WHITE: [u8; 3] = [255, 255, 255];
+const BLACK: [u8; 3] = [0, 0, 0];
+const SPRITE: [u8; 3] = [140, 180, 230];
+const BEAM: [u8; 3] = [220, 20, 60];
+
+// A plain (ASCII) PBM, where 1 is a lit pixel.
+pub fn pbm(screen: &Bitmap) -> String {
+    let mut out = format!("P1\n{} {}\n", screen.width(), screen.height());
+    for row in screen.rows() {
+        let row: Vec<&str> = row.iter().map(|lit| if *lit { "1" } else { "0" }).collect();
+        out.push_str(&row.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+// A greyscale PNG, with every pixel blown up to a `scale` sided square.
+pub fn write_png(screen: &Bitmap, scale: usize, writer: impl Write) -> Result<()> {
+    let pixels: Vec<u8> = screen
+        .rows()
+        .flatten()
+        .map(|lit| if *lit { BLACK[0] } else { WHITE[0] })
+        .collect();
+
+    let (width, height) = size(screen.width(), screen.height(), scale, "PNG")?;
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()?
+        .write_image_data(&scaled(screen.width(), &pixels, scale))?;
+    Ok(())
+}
+
+// Records what the screen looks like during every cycle: what's been drawn so
+// far, the sprite's three columns on the beam's row, and the pixel under the
+// beam.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    crt: Crt,
+    frames: Vec<Vec<u8>>,
+}
+
+impl Animation {
+    pub fn new(width: usize, height: usize) -> Self {
+        Animation {
+            crt: Crt::new(width, height),
+            frames: vec![],
+        }
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    // `delay` is how long each frame is shown, in hundredths of a second.
+    pub fn write_gif(&self, scale: usize, delay: u16, writer: impl Write) -> Result<()> {
+        let (width, height) = size(self.crt.width(), self.crt.height(), scale, "GIF")?;
+        let palette = [WHITE, BLACK, SPRITE, BEAM].concat();
+        let mut encoder = gif::Encoder::new(writer, width, height, &palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        for frame in &self.frames {
+            let mut frame = gif::Frame::from_indexed_pixels(
+                width,
+                height,
+                scaled(self.crt.width(), frame, scale),
+                None,
+            );
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl Observer for Animation {
+    fn during(&mut self, cycle: u64, registers: &Registers) {
+        let Some((beam_x, beam_y)) = self.crt.beam(cycle) else {
+            return;
+        };
+        self.crt.during(cycle, registers);
+
+        let sprite = registers[Register::X];
+        let mut frame = Vec::with_capacity(self.crt.width() * self.crt.height());
+        for (y, row) in self.crt.screen().rows().enumerate() {
+            for (x, lit) in row.iter().enumerate() {
+                frame.push(if (x, y) == (beam_x, beam_y) {
+                    3
+                } else if y == beam_y && sprite_covers(sprite, x) {
+                    2
+                } else {
+                    u8::from(*lit)
+                });
+            }
+        }
+        self.frames.push(frame);
+    }
+}
+
+// The size of the scaled up image, as long as `format` can hold it.
+fn size<T: TryFrom<usize>>(
+    width: usize,
+    height: usize,
+    scale: usize,
+    format: &str,
+) -> Result<(T, T)> {
+    if scale == 0 {
+        return Err(anyhow!("images have to be scaled by at least 1"));
+    }
+    let scaled = |length: usize| {
+        length
+            .checked_mul(scale)
+            .and_then(|length| T::try_from(length).ok())
+            .ok_or_else(|| {
+                anyhow!(
+                    "a {} can't be {} pixels across, try a smaller scale",
+                    format,
+                    length as u128 * scale as u128
+                )
+            })
+    };
+    Ok((scaled(width)?, scaled(height)?))
+}
+
+fn scaled(width: usize, pixels: &[u8], scale: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(pixels.len() * scale * scale);
+    for row in pixels.chunks(width) {
+        let row: Vec<u8> = row
+            .iter()
+            .flat_map(|pixel| std::iter::repeat_n(*pixel, scale))
+            .collect();
+        for _ in 0..scale {
+            out.extend_from_slice(&row);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::{vm::Cpu, Day10};
+
+    use super::*;
+
+    fn sample() -> Cpu {
+        Cpu::new(Day10::parse(&read("sample.txt").unwrap()).unwrap())
+    }
+
+    #[test]
+    fn test_pbm() {
+        let mut crt = Crt::new(4, 2);
+        sample().run_observed(&mut [&mut crt]).unwrap();
+        assert_eq!("P1\n4 2\n1 1 0 0\n0 0 0 0\n", pbm(crt.screen()));
+    }
+
+    #[test]
+    fn test_png() {
+        let mut crt = Crt::new(40, 6);
+        sample().run_observed(&mut [&mut crt]).unwrap();
+        let mut out = vec![];
+        write_png(crt.screen(), 2, &mut out).unwrap();
+
+        let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((80, 12), (info.width, info.height));
+        assert_eq!([0, 0, 0, 0, 255, 255], pixels[..6]);
+    }
+
+    #[test]
+    fn test_animation() {
+        let mut animation = Animation::new(40, 6);
+        sample().run_observed(&mut [&mut animation]).unwrap();
+        assert_eq!(240, animation.frames());
+        // During cycle 3 the beam is on the third pixel, after two lit ones,
+        // and X has already moved the sprite off to the right.
+        assert_eq!([1, 1, 3, 0], animation.frames[2][..4]);
+        assert_eq!([2, 2, 2], animation.frames[2][15..18]);
+        assert_eq!([1, 1, 0, 0], animation.frames[42][..4]);
+
+        let mut out = vec![];
+        animation.write_gif(1, 5, &mut out).unwrap();
+        assert!(out.starts_with(b"GIF89a"));
+
+        let err = animation.write_gif(2000, 5, vec![]).unwrap_err();
+        assert!(err.to_string().contains("80000 pixels"));
+        assert!(animation.write_gif(0, 5, vec![]).is_err());
+    }
+}
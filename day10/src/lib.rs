@@ -0,0 +1,111 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, parse, Answer, Solution};
+use vm::{Opcode, INSTRUCTION_SET};
+
+pub mod asm;
+pub mod crt;
+pub mod debugger;
+pub mod image;
+pub mod observers;
+pub mod ocr;
+pub mod part1;
+pub mod part2;
+pub mod vm;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(input::parse_lines(text)?)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(instructions)?.into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        part2::solve(instructions)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    // 0 for opcodes that don't take one.
+    pub operand: i64,
+}
+
+impl Instruction {
+    pub fn noop() -> Self {
+        Instruction {
+            opcode: Opcode::Noop,
+            operand: 0,
+        }
+    }
+
+    pub fn addx(value: i64) -> Self {
+        Instruction {
+            opcode: Opcode::Addx,
+            operand: value,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::line(line, |cursor| {
+            cursor.skip_whitespace();
+            let mnemonics: Vec<&str> = INSTRUCTION_SET.iter().map(|op| op.mnemonic).collect();
+            let mnemonic = cursor.keyword(&mnemonics)?;
+            let info = INSTRUCTION_SET
+                .iter()
+                .find(|op| op.mnemonic == mnemonic)
+                .unwrap();
+
+            let mut operand = 0;
+            if info.takes_operand {
+                cursor.skip_whitespace();
+                operand = cursor.integer("a number")?;
+            }
+            Ok(Instruction {
+                opcode: info.opcode,
+                operand,
+            })
+        })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.opcode.info();
+        if info.takes_operand {
+            write!(f, "{} {}", info.mnemonic, self.operand)
+        } else {
+            write!(f, "{}", info.mnemonic)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(Instruction::noop(), "noop".parse().unwrap());
+        assert_eq!(Instruction::addx(-11), "addx -11".parse().unwrap());
+        assert_eq!("addx -11", Instruction::addx(-11).to_string());
+
+        let err = "addx".parse::<Instruction>().unwrap_err();
+        assert_eq!((5, "a number"), (err.column, err.expected.as_str()));
+
+        let err = "mulx 3".parse::<Instruction>().unwrap_err();
+        assert_eq!((1, "mulx"), (err.column, err.found.as_str()));
+    }
+}
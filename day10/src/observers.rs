@@ -0,0 +1,83 @@
+use crate::vm::{Observer, Register, Registers};
+
+// Samples X times the cycle number during the 20th cycle and every 40th
+// after it. A sample too big for an i64 isn't kept, just the first cycle one
+// happened in.
+#[derive(Clone, Debug, Default)]
+pub struct SignalStrength {
+    pub samples: Vec<(u64, i64)>,
+    pub overflowed: Option<u64>,
+}
+
+impl SignalStrength {
+    // None if a sample or the sum of them doesn't fit in an i64.
+    pub fn total(&self) -> Option<i64> {
+        if self.overflowed.is_some() {
+            return None;
+        }
+        self.samples
+            .iter()
+            .try_fold(0i64, |total, (_, strength)| total.checked_add(*strength))
+    }
+}
+
+impl Observer for SignalStrength {
+    fn during(&mut self, cycle: u64, registers: &Registers) {
+        if cycle >= 20 && (cycle - 20).is_multiple_of(40) {
+            let strength = i64::try_from(cycle)
+                .ok()
+                .and_then(|cycle| cycle.checked_mul(registers[Register::X]));
+            match strength {
+                Some(strength) => self.samples.push((cycle, strength)),
+                None => {
+                    self.overflowed.get_or_insert(cycle);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::{crt::Crt, vm::Cpu, Day10};
+
+    use super::*;
+
+    #[test]
+    fn test_observers() {
+        let program = Day10::parse(&read("sample.txt").unwrap()).unwrap();
+        let mut signal = SignalStrength::default();
+        let mut crt = Crt::new(4, 1);
+        Cpu::new(program)
+            .run_observed(&mut [&mut signal, &mut crt])
+            .unwrap();
+
+        assert_eq!((20, 420), signal.samples[0]);
+        assert_eq!(6, signal.samples.len());
+        assert_eq!("##..", crt.to_string());
+        assert_eq!(Some(13140), signal.total());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut signal = SignalStrength::default();
+        let mut registers = Registers::default();
+        registers[Register::X] = i64::MAX / 20;
+        signal.during(20, &registers);
+        assert_eq!(Some(i64::MAX / 20 * 20), signal.total());
+
+        signal.during(60, &registers);
+        registers[Register::X] = i64::MIN;
+        signal.during(100, &registers);
+        assert_eq!((1, Some(60)), (signal.samples.len(), signal.overflowed));
+        assert_eq!(None, signal.total());
+
+        let mut signal = SignalStrength::default();
+        registers[Register::X] = i64::MAX / 60;
+        signal.during(20, &registers);
+        signal.during(60, &registers);
+        assert_eq!(None, signal.total());
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::{anyhow, Result};
+
+use aoc_common::Bitmap;
+
+// The capital letters Advent of Code draws, each 4 pixels wide and 6 high with
+// a blank column between them. Y is the exception: its top right arm spills
+// into that column, which `read` never looks at.
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// Reads the letters off a screen, left to right. Blank space after the last
+// letter is fine, but anything else that isn't in the font is an error.
+pub fn read(screen: &Bitmap) -> Result<String> {
+    if screen.height() != GLYPH_HEIGHT {
+        return Err(anyhow!(
+            "letters are {} pixels high, but the screen is {}",
+            GLYPH_HEIGHT,
+            screen.height()
+        ));
+    }
+
+    let glyphs: Vec<Vec<String>> = (0..screen.width())
+        .step_by(GLYPH_WIDTH + 1)
+        .map(|left| glyph(screen, left))
+        .collect();
+    let blank = ".".repeat(GLYPH_WIDTH);
+    let used = glyphs
+        .iter()
+        .rposition(|rows| rows.iter().any(|row| *row != blank))
+        .map_or(0, |last| last + 1);
+
+    glyphs[..used]
+        .iter()
+        .enumerate()
+        .map(|(i, rows)| {
+            FONT.iter()
+                .find(|(_, pattern)| pattern.iter().eq(rows.iter()))
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "letter {} on the screen isn't one I know:\n{}",
+                        i + 1,
+                        rows.join("\n")
+                    )
+                })
+        })
+        .collect()
+}
+
+fn glyph(screen: &Bitmap, left: usize) -> Vec<String> {
+    (0..GLYPH_HEIGHT)
+        .map(|y| {
+            (left..left + GLYPH_WIDTH)
+                .map(|x| if screen.get(x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &[&str]) -> Bitmap {
+        Bitmap::from_pixels(rows[0].len(), &rows.concat())
+    }
+
+    #[test]
+    fn test_read() {
+        let letters = screen(&[
+            "###..####.####.#..#..........",
+            "#..#....#.#....#.#...........",
+            "#..#...#..###..##............",
+            "###...#...#....#.#...........",
+            "#.#..#....#....#.#...........",
+            "#..#.####.####.#..#..........",
+        ]);
+        assert_eq!("RZEK", read(&letters).unwrap());
+
+        let letters = screen(&[
+            ".###.#...#",
+            "..#..#...#",
+            "..#...#.#.",
+            "..#....#..",
+            "..#....#..",
+            ".###...#..",
+        ]);
+        assert_eq!("IY", read(&letters).unwrap());
+
+        let unknown = screen(&["#...", "....", "....", "....", "....", "...."]);
+        assert!(read(&unknown).unwrap_err().to_string().contains("letter 1"));
+        assert!(read(&Bitmap::blank(40, 5)).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::{anyhow, Result};
+use tracing::debug;
+
+use crate::{observers::SignalStrength, vm::Cpu, Instruction};
+
+pub fn solve(instructions: &[Instruction]) -> Result<i64> {
+    let mut signal = SignalStrength::default();
+    Cpu::new(instructions.to_vec()).run_observed(&mut [&mut signal])?;
+
+    debug!("Signal strengths: {:?}", signal.samples);
+    match (signal.total(), signal.overflowed) {
+        (Some(total), _) => Ok(total),
+        (None, Some(cycle)) => Err(anyhow!(
+            "the signal strength during cycle {} is too big for an i64",
+            cycle
+        )),
+        (None, None) => Err(anyhow!("the signal strengths add up to more than an i64")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day10;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 13140;
+        let input = Day10::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use aoc_common::Answer;
+use tracing::debug;
+
+use crate::{crt::Crt, ocr, vm::Cpu, Instruction};
+
+pub fn render(instructions: &[Instruction]) -> Result<Crt> {
+    let mut crt = Crt::new(40, 6);
+    Cpu::new(instructions.to_vec()).run_observed(&mut [&mut crt])?;
+    Ok(crt)
+}
+
+// The letters on the screen, or the screen itself for programs that draw
+// something else.
+pub fn solve(instructions: &[Instruction]) -> Result<Answer> {
+    let crt = render(instructions)?;
+    debug!("Screen:\n{}", crt);
+    match ocr::read(crt.screen()) {
+        Ok(letters) => Ok(letters.into()),
+        Err(e) => {
+            debug!("Couldn't read the screen: {}", e);
+            Ok(crt.screen().clone().into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day10;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        let input = Day10::parse(&read("sample.txt").unwrap()).unwrap();
+        assert_eq!(expected, render(&input).unwrap().to_string());
+        // The sample doesn't draw letters.
+        assert!(matches!(solve(&input).unwrap(), Answer::Bitmap(_)));
+    }
+}
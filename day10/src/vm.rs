@@ -0,0 +1,317 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::Instruction;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Register {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::X, Register::Y, Register::Z, Register::W];
+
+    pub fn name(self) -> &'static str {
+        ["x", "y", "z", "w"][self as usize]
+    }
+}
+
+// X starts at 1 and the rest at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers([i64; 4]);
+
+impl Default for Registers {
+    fn default() -> Self {
+        Registers([1, 0, 0, 0])
+    }
+}
+
+impl Index<Register> for Registers {
+    type Output = i64;
+
+    fn index(&self, register: Register) -> &i64 {
+        &self.0[register as usize]
+    }
+}
+
+impl IndexMut<Register> for Registers {
+    fn index_mut(&mut self, register: Register) -> &mut i64 {
+        &mut self.0[register as usize]
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, register) in Register::ALL.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", register.name(), self[*register])?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Noop,
+    Addx,
+}
+
+// What an opcode looks like and does. An instruction takes `cycles` cycles,
+// and its effect only lands once the last of them is over. `execute` returns
+// None, leaving the registers alone, if a register would overflow.
+pub struct OpcodeInfo {
+    pub opcode: Opcode,
+    pub mnemonic: &'static str,
+    pub cycles: u32,
+    pub takes_operand: bool,
+    pub execute: fn(&mut Registers, i64) -> Option<()>,
+}
+
+// Indexed by `Opcode`, so the rows have to be in the same order as its
+// variants. Adding an opcode needs a variant, a row here in the matching
+// place, and the array's length bumped.
+pub const INSTRUCTION_SET: [OpcodeInfo; 2] = [
+    OpcodeInfo {
+        opcode: Opcode::Noop,
+        mnemonic: "noop",
+        cycles: 1,
+        takes_operand: false,
+        execute: |_, _| Some(()),
+    },
+    OpcodeInfo {
+        opcode: Opcode::Addx,
+        mnemonic: "addx",
+        cycles: 2,
+        takes_operand: true,
+        execute: |registers, value| {
+            registers[Register::X] = registers[Register::X].checked_add(value)?;
+            Some(())
+        },
+    },
+];
+
+impl Opcode {
+    pub fn info(self) -> &'static OpcodeInfo {
+        &INSTRUCTION_SET[self as usize]
+    }
+}
+
+// Something watching the CPU run, told about every cycle while it's happening.
+pub trait Observer {
+    fn during(&mut self, cycle: u64, registers: &Registers);
+}
+
+impl<F: FnMut(u64, &Registers)> Observer for F {
+    fn during(&mut self, cycle: u64, registers: &Registers) {
+        self(cycle, registers)
+    }
+}
+
+// An instruction that would have taken a register out of range. The CPU stops
+// just before the end of the cycle it happened in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub cycle: u64,
+    pub instruction_pointer: usize,
+    pub instruction: Instruction,
+    pub registers: Registers,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at ip {} overflows in cycle {} ({})",
+            self.instruction, self.instruction_pointer, self.cycle, self.registers
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+#[derive(Clone, Debug)]
+pub struct Cpu {
+    program: Vec<Instruction>,
+    // The instruction running, or the next one to start.
+    pub instruction_pointer: usize,
+    // The cycle that's about to run, counting from 1. Between steps the
+    // registers hold their values "during" this cycle.
+    pub cycle: u64,
+    pub registers: Registers,
+    // How many cycles the current instruction has had so far.
+    elapsed: u32,
+}
+
+impl Cpu {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Cpu {
+            program,
+            instruction_pointer: 0,
+            cycle: 1,
+            registers: Registers::default(),
+            elapsed: 0,
+        }
+    }
+
+    pub fn x(&self) -> i64 {
+        self.registers[Register::X]
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn current_instruction(&self) -> Option<Instruction> {
+        self.program.get(self.instruction_pointer).copied()
+    }
+
+    // Once the program has run off its end there's nothing left to do.
+    pub fn is_halted(&self) -> bool {
+        self.instruction_pointer >= self.program.len()
+    }
+
+    // Whether the next cycle is the first of the current instruction.
+    pub fn starting_instruction(&self) -> bool {
+        self.elapsed == 0
+    }
+
+    // Runs one cycle, returning false without doing anything if halted. On an
+    // overflow nothing changes, so the CPU stays stuck on the same cycle.
+    pub fn step(&mut self) -> Result<bool, Overflow> {
+        let Some(instruction) = self.current_instruction() else {
+            return Ok(false);
+        };
+
+        let info = instruction.opcode.info();
+        if self.elapsed + 1 >= info.cycles {
+            (info.execute)(&mut self.registers, instruction.operand).ok_or(Overflow {
+                cycle: self.cycle,
+                instruction_pointer: self.instruction_pointer,
+                instruction,
+                registers: self.registers,
+            })?;
+            self.instruction_pointer += 1;
+            self.elapsed = 0;
+        } else {
+            self.elapsed += 1;
+        }
+
+        self.cycle += 1;
+        Ok(true)
+    }
+
+    // Steps until `stop` is true before a cycle, or the program halts. Returns
+    // whether it stopped because of `stop`.
+    pub fn run_until(&mut self, mut stop: impl FnMut(&Cpu) -> bool) -> Result<bool, Overflow> {
+        loop {
+            if stop(self) {
+                return Ok(true);
+            }
+            if !self.step()? {
+                return Ok(false);
+            }
+        }
+    }
+
+    pub fn run(&mut self) -> Result<(), Overflow> {
+        while self.step()? {}
+        Ok(())
+    }
+
+    // Like `step`, but shows every observer the cycle before it runs.
+    pub fn step_observed(&mut self, observers: &mut [&mut dyn Observer]) -> Result<bool, Overflow> {
+        if self.is_halted() {
+            return Ok(false);
+        }
+        for observer in observers.iter_mut() {
+            observer.during(self.cycle, &self.registers);
+        }
+        self.step()
+    }
+
+    pub fn run_observed(&mut self, observers: &mut [&mut dyn Observer]) -> Result<(), Overflow> {
+        while self.step_observed(observers)? {}
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cpu(program: &str) -> Cpu {
+        Cpu::new(program.lines().map(|line| line.parse().unwrap()).collect())
+    }
+
+    #[test]
+    fn test_instruction_set_order() {
+        for (i, info) in INSTRUCTION_SET.iter().enumerate() {
+            assert_eq!(i, info.opcode as usize, "{} is out of order", info.mnemonic);
+        }
+    }
+
+    #[test]
+    fn test_timing() {
+        let mut cpu = cpu("noop\naddx 3\naddx -5");
+        let mut during = vec![];
+        while !cpu.is_halted() {
+            during.push((cpu.cycle, cpu.x()));
+            cpu.step().unwrap();
+        }
+
+        assert_eq!(vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)], during);
+        assert_eq!((6, -1), (cpu.cycle, cpu.x()));
+        assert!(!cpu.step().unwrap());
+        assert_eq!(6, cpu.cycle);
+    }
+
+    #[test]
+    fn test_run_observed() {
+        let mut cpu = cpu("noop\naddx 3\naddx -5");
+        let mut changes = vec![];
+        let mut last = None;
+        let mut watchpoint = |cycle: u64, registers: &Registers| {
+            if last.is_some_and(|x| x != registers[Register::X]) {
+                changes.push(cycle);
+            }
+            last = Some(registers[Register::X]);
+        };
+        let mut cycles = 0;
+        let mut counter = |_: u64, _: &Registers| cycles += 1;
+
+        cpu.run_observed(&mut [&mut watchpoint, &mut counter])
+            .unwrap();
+        assert_eq!(vec![4], changes);
+        assert_eq!(5, cycles);
+    }
+
+    #[test]
+    fn test_run_until() {
+        let mut cpu = cpu("noop\naddx 3\naddx -5");
+        assert!(cpu.run_until(|cpu| cpu.instruction_pointer == 2).unwrap());
+        assert_eq!((4, 4), (cpu.cycle, cpu.x()));
+
+        assert!(!cpu.run_until(|cpu| cpu.cycle == 100).unwrap());
+        assert!(cpu.is_halted());
+        assert_eq!("x=-1 y=0 z=0 w=0", cpu.registers.to_string());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut cpu = cpu("addx 9223372036854775807\naddx 9223372036854775807");
+        let err = cpu.run().unwrap_err();
+        assert_eq!(
+            "addx 9223372036854775807 at ip 0 overflows in cycle 2 (x=1 y=0 z=0 w=0)",
+            err.to_string()
+        );
+        assert_eq!((2, 0, 1), (cpu.cycle, cpu.instruction_pointer, cpu.x()));
+        assert!(cpu.step().is_err());
+    }
+}
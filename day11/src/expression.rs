@@ -0,0 +1,186 @@
+use std::fmt;
+
+use aoc_common::{error::ParseError, parse::Cursor};
+
+use crate::worry::Worry;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    fn symbol(self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+        }
+    }
+
+    fn from_symbol(symbol: &str) -> Self {
+        match symbol {
+            "+" => Operator::Add,
+            "-" => Operator::Subtract,
+            "*" => Operator::Multiply,
+            _ => Operator::Divide,
+        }
+    }
+}
+
+// The right hand side of a monkey's "new = ..." operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expression {
+    Old,
+    Constant(u64),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression {
+    pub fn binary(left: Expression, operator: Operator, right: Expression) -> Self {
+        Expression::Binary(Box::new(left), operator, Box::new(right))
+    }
+
+    // None if any step overflows, goes below zero or divides by zero.
+    pub fn evaluate<W: Worry>(&self, old: &W) -> Option<W> {
+        match self {
+            Expression::Old => Some(old.clone()),
+            Expression::Constant(value) => Some(W::from_u64(*value)),
+            Expression::Binary(left, operator, right) => {
+                let (left, right) = (left.evaluate(old)?, right.evaluate(old)?);
+                match operator {
+                    Operator::Add => left.checked_add(&right),
+                    Operator::Subtract => left.checked_sub(&right),
+                    Operator::Multiply => left.checked_mul(&right),
+                    Operator::Divide => left.checked_div(&right),
+                }
+            }
+        }
+    }
+
+    // Whether working the expression out on a remainder gives the remainder
+    // of the full result, modulo anything. Only true without - and /.
+    pub fn keeps_remainders(&self) -> bool {
+        match self {
+            Expression::Old | Expression::Constant(_) => true,
+            Expression::Binary(left, operator, right) => {
+                matches!(operator, Operator::Add | Operator::Multiply)
+                    && left.keeps_remainders()
+                    && right.keeps_remainders()
+            }
+        }
+    }
+
+    // The usual precedence: * and / bind tighter than + and -, and everything
+    // groups to the left.
+    pub fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let expression = sum(cursor)?;
+        if !cursor.at_end() {
+            return Err(cursor.error("\"+\", \"-\", \"*\" or \"/\""));
+        }
+        Ok(expression)
+    }
+}
+
+fn sum(cursor: &mut Cursor) -> Result<Expression, ParseError> {
+    let mut expression = product(cursor)?;
+    while let Ok(symbol) = cursor.keyword(&["+", "-"]) {
+        expression =
+            Expression::binary(expression, Operator::from_symbol(symbol), product(cursor)?);
+    }
+    Ok(expression)
+}
+
+fn product(cursor: &mut Cursor) -> Result<Expression, ParseError> {
+    let mut expression = operand(cursor)?;
+    while let Ok(symbol) = cursor.keyword(&["*", "/"]) {
+        let divisor = *cursor;
+        let right = operand(cursor)?;
+        if symbol == "/" && right == Expression::Constant(0) {
+            *cursor = divisor;
+            cursor.skip_whitespace();
+            return Err(cursor.error("a divisor other than 0"));
+        }
+        expression = Expression::binary(expression, Operator::from_symbol(symbol), right);
+    }
+    Ok(expression)
+}
+
+fn operand(cursor: &mut Cursor) -> Result<Expression, ParseError> {
+    cursor.skip_whitespace();
+    let expression = if cursor.peek() == Some('(') {
+        cursor.bracketed("(", ")", sum)?
+    } else if cursor.keyword(&["old"]).is_ok() {
+        Expression::Old
+    } else {
+        Expression::Constant(cursor.integer("\"old\", a number or \"(\"")?)
+    };
+    cursor.skip_whitespace();
+    Ok(expression)
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expression::Old => write!(f, "old"),
+            Expression::Constant(value) => write!(f, "{}", value),
+            Expression::Binary(left, operator, right) => {
+                for (i, operand) in [left, right].into_iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " {} ", operator.symbol())?;
+                    }
+                    match operand.as_ref() {
+                        Expression::Binary(..) => write!(f, "({})", operand)?,
+                        operand => write!(f, "{}", operand)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse;
+
+    use super::*;
+
+    fn parse(text: &str) -> Result<Expression, ParseError> {
+        parse::line(text, Expression::parse)
+    }
+
+    #[test]
+    fn test_precedence() {
+        let expression = parse("old + 2 * (old - 1) / 3").unwrap();
+        assert_eq!("old + ((2 * (old - 1)) / 3)", expression.to_string());
+        assert_eq!(Some(10 + 2 * 9 / 3), expression.evaluate(&10u64));
+        assert_eq!(None, parse("old - 1").unwrap().evaluate(&0u64));
+        assert!(parse("old * (old + 3)").unwrap().keeps_remainders());
+        assert!(!parse("old / 2 + 3").unwrap().keeps_remainders());
+
+        assert_eq!(
+            Expression::binary(Expression::Old, Operator::Multiply, Expression::Old),
+            parse("old * old").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse("old ^ 2").unwrap_err();
+        assert_eq!((5, "^"), (err.column, err.found.as_str()));
+
+        let err = parse("(old + 1").unwrap_err();
+        assert_eq!((9, "\")\""), (err.column, err.expected.as_str()));
+
+        let err = parse("old / 0").unwrap_err();
+        assert_eq!(
+            (7, "a divisor other than 0"),
+            (err.column, err.expected.as_str())
+        );
+    }
+}
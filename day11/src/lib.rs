@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_common::{
+    error::ParseError,
+    input,
+    parse::{self, Cursor},
+    Answer, Solution,
+};
+use expression::Expression;
+
+pub mod expression;
+pub mod part1;
+pub mod part2;
+pub mod troop;
+pub mod worry;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<MonkeySpec>;
+
+    // Monkeys can have any ids, but they have to be different and every
+    // monkey has to throw to ones that exist.
+    fn parse(text: &str) -> Result<Self::Input> {
+        let blocks = input::blocks(text);
+        let monkeys = blocks
+            .iter()
+            .map(|block| MonkeySpec::parse(block))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let ids: HashSet<u64> = monkeys.iter().map(|m| m.id).collect();
+        for (i, (monkey, block)) in monkeys.iter().zip(&blocks).enumerate() {
+            if monkeys[..i].iter().any(|m| m.id == monkey.id) {
+                let expected = "a monkey number that isn't taken";
+                return Err(last_word_error(block[0], expected).into());
+            }
+        }
+
+        for (monkey, block) in monkeys.iter().zip(&blocks) {
+            for (target, line) in [(monkey.if_true, block[4]), (monkey.if_false, block[5])] {
+                if !ids.contains(&target) {
+                    let mut ids: Vec<&u64> = ids.iter().collect();
+                    ids.sort();
+                    let expected = format!("one of the monkeys {:?}", ids);
+                    return Err(last_word_error(line, &expected).into());
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(monkeys)?.into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(monkeys)?.into())
+    }
+}
+
+// A monkey as described by its block of notes, before any items get thrown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonkeySpec {
+    pub id: u64,
+    pub items: Vec<u64>,
+    pub operation: Expression,
+    pub divisible_by: u64,
+    pub if_true: u64,
+    pub if_false: u64,
+}
+
+const FIELDS: [&str; 6] = [
+    "Monkey",
+    "Starting items:",
+    "Operation: new =",
+    "Test: divisible by",
+    "If true: throw to monkey",
+    "If false: throw to monkey",
+];
+
+// Points at the number a monkey line ends with, like its id or target.
+fn last_word_error((number, line): (usize, &str), expected: &str) -> ParseError {
+    let word = line.trim_end().rsplit(' ').next().unwrap();
+    ParseError::at(line, word.trim_end_matches(':'), expected).at_line(number, line)
+}
+
+// Parses the `i`th line of a monkey's block, after checking it starts with the
+// right field name.
+fn field<'a, T>(
+    block: &[(usize, &'a str)],
+    i: usize,
+    value: impl FnOnce(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let Some(&(number, line)) = block.get(i) else {
+        let (last_number, _) = block[block.len() - 1];
+        let expected = format!("\"{}\"", FIELDS[i]);
+        return Err(ParseError::new(1, expected, "").at_line(last_number + 1, ""));
+    };
+
+    parse::line(line, |cursor| {
+        cursor.skip_whitespace();
+        cursor.literal(FIELDS[i])?;
+        cursor.skip_whitespace();
+        value(cursor)
+    })
+    .map_err(|e| e.at_line(number, line))
+}
+
+impl MonkeySpec {
+    fn parse(block: &[(usize, &str)]) -> Result<Self, ParseError> {
+        let monkey = |cursor: &mut Cursor| cursor.integer("a monkey number");
+
+        let id = field(block, 0, |cursor| {
+            let id = monkey(cursor)?;
+            cursor.literal(":")?;
+            Ok(id)
+        })?;
+        let items = field(block, 1, |cursor| {
+            // A monkey can start out empty handed.
+            if cursor.at_end() {
+                return Ok(vec![]);
+            }
+            cursor.separated(",", |cursor| {
+                cursor.skip_whitespace();
+                cursor.integer("a worry level")
+            })
+        })?;
+        let operation = field(block, 2, Expression::parse)?;
+        let divisible_by = field(block, 3, |cursor| {
+            let divisor = *cursor;
+            match cursor.integer("a number")? {
+                0 => Err(divisor.error("a number other than 0")),
+                divisible_by => Ok(divisible_by),
+            }
+        })?;
+        let if_true = field(block, 4, monkey)?;
+        let if_false = field(block, 5, monkey)?;
+
+        if let Some(&(number, line)) = block.get(FIELDS.len()) {
+            let found = line.trim_start();
+            return Err(
+                ParseError::at(line, found, "a blank line before the next monkey")
+                    .at_line(number, line),
+            );
+        }
+
+        Ok(MonkeySpec {
+            id,
+            items,
+            operation,
+            divisible_by,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONKEY: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+";
+
+    #[test]
+    fn test_parse() {
+        let monkeys = Day11::parse(&input::read("sample.txt").unwrap()).unwrap();
+        assert_eq!(4, monkeys.len());
+        assert_eq!(
+            MonkeySpec {
+                id: 0,
+                items: vec![79, 98],
+                operation: Expression::binary(
+                    Expression::Old,
+                    expression::Operator::Multiply,
+                    Expression::Constant(19)
+                ),
+                divisible_by: 23,
+                if_true: 2,
+                if_false: 3,
+            },
+            monkeys[0]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let text = MONKEY.replace("old * 19", "old ^ 19");
+        let err = Day11::parse(&text).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((3, 24), (err.line, err.column));
+
+        let text = MONKEY.replace("79, 98", "79, x");
+        let err = Day11::parse(&text).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 23, "x"), (err.line, err.column, err.found.as_str()));
+
+        let text = format!("{}\n{}", MONKEY, MONKEY.replace("Monkey 0", "Monkey 12"));
+        let err = Day11::parse(&text).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((5, 30, "2"), (err.line, err.column, err.found.as_str()));
+
+        let text = format!("{}\n{}", MONKEY, MONKEY);
+        let err = Day11::parse(&text).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((8, 8, "0"), (err.line, err.column, err.found.as_str()));
+
+        let text = MONKEY.replace("    If false: throw to monkey 3\n", "");
+        let err = Day11::parse(&text).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (6, "\"If false: throw to monkey\""),
+            (err.line, err.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_custom_troop() {
+        let text = "Monkey 7:
+  Starting items: 10
+  Operation: new = (old + 2) * 2 - old / 4
+  Test: divisible by 2
+    If true: throw to monkey 42
+    If false: throw to monkey 7
+
+Monkey 42:
+  Starting items:
+  Operation: new = old
+  Test: divisible by 5
+    If true: throw to monkey 7
+    If false: throw to monkey 42
+";
+        let monkeys = Day11::parse(text).unwrap();
+        assert_eq!(
+            "((old + 2) * 2) - (old / 4)",
+            monkeys[0].operation.to_string()
+        );
+        assert_eq!((7, 42), (monkeys[1].if_true, monkeys[1].id));
+        assert!(monkeys[1].items.is_empty());
+        // The item goes 10, 7, 5 with monkey 7, then bounces between the two
+        // every other round: 19 inspections for monkey 7, 10 for monkey 42.
+        assert_eq!(190, part1::solve(&monkeys).unwrap());
+    }
+}
@@ -0,0 +1,31 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    troop::{MonkeyTroop, SimulationConfig},
+    MonkeySpec,
+};
+
+pub fn solve(monkeys: &[MonkeySpec]) -> Result<u64> {
+    let mut troop: MonkeyTroop = MonkeyTroop::new(monkeys);
+    troop.run(&SimulationConfig::part1())?;
+    let business = troop.monkey_business(2);
+    u64::try_from(business)
+        .map_err(|_| anyhow!("monkey business {} doesn't fit in a u64", business))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day11;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 10605;
+        let input = Day11::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
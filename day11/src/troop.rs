@@ -0,0 +1,602 @@
+use std::{collections::HashMap, fmt};
+
+use serde::Serialize;
+
+use crate::{
+    expression::{Expression, Operator},
+    worry::{Overflow, Worry},
+    MonkeySpec,
+};
+
+// How worry levels are kept from growing without bound. Every test only cares
+// about a worry level modulo its divisor, so reducing modulo a common multiple
+// of all of them doesn't change where anything gets thrown, as long as the
+// operations and relief rule only add and multiply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+    None,
+    Product,
+    Lcm,
+}
+
+#[derive(Clone, Debug)]
+pub struct SimulationConfig {
+    pub rounds: usize,
+    // Applied to each item after it's inspected, before it's tested, with
+    // `old` being the worry level the operation came up with.
+    pub relief: Expression,
+    pub reduction: Reduction,
+}
+
+impl SimulationConfig {
+    // 20 rounds, with worry dropping to a third after each inspection.
+    pub fn part1() -> Self {
+        SimulationConfig {
+            rounds: 20,
+            relief: Expression::binary(Expression::Old, Operator::Divide, Expression::Constant(3)),
+            reduction: Reduction::None,
+        }
+    }
+
+    // 10,000 rounds with no relief, which only stays small with reduction.
+    pub fn part2() -> Self {
+        SimulationConfig {
+            rounds: 10_000,
+            relief: Expression::Old,
+            reduction: Reduction::Lcm,
+        }
+    }
+}
+
+// Why a simulation couldn't run, or stopped partway through.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimulationError {
+    Overflow(Overflow),
+    // Says which rule subtracts or divides.
+    Unreducible(String),
+    // The common multiple of the divisors doesn't fit in a u64.
+    ModulusOverflow(Reduction),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::Overflow(overflow) => write!(f, "{}", overflow),
+            SimulationError::Unreducible(rule) => write!(
+                f,
+                "worry levels can't be reduced, since {} subtracts or divides, run without reduction instead",
+                rule
+            ),
+            SimulationError::ModulusOverflow(reduction) => write!(
+                f,
+                "the {} of the divisors is too big to reduce worry levels by",
+                match reduction {
+                    Reduction::Lcm => "lowest common multiple",
+                    _ => "product",
+                }
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+impl From<Overflow> for SimulationError {
+    fn from(overflow: Overflow) -> Self {
+        SimulationError::Overflow(overflow)
+    }
+}
+
+// An item thrown during a round, with the worry level it was thrown at.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Transfer {
+    pub from: u64,
+    pub to: u64,
+    pub worry: u64,
+}
+
+// How many items a monkey inspected during a round.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Inspection {
+    pub monkey: u64,
+    pub inspected: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Round {
+    pub round: usize,
+    pub transfers: Vec<Transfer>,
+    pub inspections: Vec<Inspection>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct History {
+    pub rounds: Vec<Round>,
+}
+
+impl History {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    // One row per transfer and one per monkey per round with how many items it
+    // inspected, in a single table. Each kind leaves the other's columns empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("round,kind,monkey,to,worry,inspected\n");
+        for round in &self.rounds {
+            for t in &round.transfers {
+                csv.push_str(&format!(
+                    "{},transfer,{},{},{},\n",
+                    round.round, t.from, t.to, t.worry
+                ));
+            }
+            for i in &round.inspections {
+                csv.push_str(&format!(
+                    "{},inspection,{},,,{}\n",
+                    round.round, i.monkey, i.inspected
+                ));
+            }
+        }
+        csv
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Monkey<W> {
+    id: u64,
+    items: Vec<W>,
+    operation: Expression,
+    divisible_by: u64,
+    // Indexes into the troop rather than ids.
+    if_true: usize,
+    if_false: usize,
+    inspected: u64,
+}
+
+// Worry levels are u64 by default, which is plenty with reduction. Without
+// it they can grow past any fixed size, so use BigUint instead.
+#[derive(Clone, Debug)]
+pub struct MonkeyTroop<W = u64> {
+    monkeys: Vec<Monkey<W>>,
+}
+
+impl<W: Worry> MonkeyTroop<W> {
+    // The specs have to have been validated, so every target exists.
+    pub fn new(specs: &[MonkeySpec]) -> Self {
+        let mut specs: Vec<&MonkeySpec> = specs.iter().collect();
+        specs.sort_by_key(|spec| spec.id);
+        let index: HashMap<u64, usize> = specs
+            .iter()
+            .enumerate()
+            .map(|(i, spec)| (spec.id, i))
+            .collect();
+
+        let monkeys = specs
+            .iter()
+            .map(|spec| Monkey {
+                id: spec.id,
+                items: spec.items.iter().map(|item| W::from_u64(*item)).collect(),
+                operation: spec.operation.clone(),
+                divisible_by: spec.divisible_by,
+                if_true: index[&spec.if_true],
+                if_false: index[&spec.if_false],
+                inspected: 0,
+            })
+            .collect();
+
+        MonkeyTroop { monkeys }
+    }
+
+    // What worry levels are reduced modulo, if anything. An error if the
+    // reduction would change where items get thrown.
+    pub fn modulus(&self, config: &SimulationConfig) -> Result<Option<u64>, SimulationError> {
+        if config.reduction == Reduction::None {
+            return Ok(None);
+        }
+        if let Some(monkey) = self
+            .monkeys
+            .iter()
+            .find(|m| !m.operation.keeps_remainders())
+        {
+            return Err(SimulationError::Unreducible(format!(
+                "monkey {}'s operation new = {}",
+                monkey.id, monkey.operation
+            )));
+        }
+        if !config.relief.keeps_remainders() {
+            return Err(SimulationError::Unreducible(format!(
+                "the relief rule new = {}",
+                config.relief
+            )));
+        }
+
+        let combine = match config.reduction {
+            Reduction::Lcm => lcm,
+            _ => u64::checked_mul,
+        };
+        self.monkeys
+            .iter()
+            .try_fold(1, |modulus, m| combine(modulus, m.divisible_by))
+            .map(Some)
+            .ok_or(SimulationError::ModulusOverflow(config.reduction))
+    }
+
+    pub fn run(&mut self, config: &SimulationConfig) -> Result<(), SimulationError> {
+        let modulus = self.modulus(config)?;
+        for _ in 0..config.rounds {
+            self.round(&config.relief, modulus, |_, _, _| {})?;
+        }
+        Ok(())
+    }
+
+    // Each monkey in turn inspects and throws everything it's holding.
+    fn round(
+        &mut self,
+        relief: &Expression,
+        modulus: Option<u64>,
+        mut on_throw: impl FnMut(u64, u64, &W),
+    ) -> Result<(), Overflow> {
+        for i in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[i].items);
+            self.monkeys[i].inspected += items.len() as u64;
+
+            for item in items {
+                let (worry, target) = self.inspect(i, &item, relief, modulus)?;
+                on_throw(self.monkeys[i].id, self.monkeys[target].id, &worry);
+                self.monkeys[target].items.push(worry);
+            }
+        }
+        Ok(())
+    }
+
+    // What monkey `i` does with an item: its new worry level and who it goes to.
+    fn inspect(
+        &self,
+        i: usize,
+        item: &W,
+        relief: &Expression,
+        modulus: Option<u64>,
+    ) -> Result<(W, usize), Overflow> {
+        let monkey = &self.monkeys[i];
+        let overflow = |expression: &Expression, old: &W| Overflow {
+            monkey: monkey.id,
+            expression: format!("new = {}", expression),
+            old: old.to_string(),
+        };
+
+        let inspected = monkey
+            .operation
+            .evaluate(item)
+            .ok_or_else(|| overflow(&monkey.operation, item))?;
+        let mut worry = relief
+            .evaluate(&inspected)
+            .ok_or_else(|| overflow(relief, &inspected))?;
+        if let Some(modulus) = modulus {
+            worry = W::from_u64(worry.rem_u64(modulus));
+        }
+
+        if worry.rem_u64(monkey.divisible_by) == 0 {
+            Ok((worry, monkey.if_true))
+        } else {
+            Ok((worry, monkey.if_false))
+        }
+    }
+
+    // Inspection counts by id after `rounds` rounds, found without simulating
+    // them all. Items never affect each other, and with worry levels reduced
+    // modulo the troop's modulus each one eventually repeats a round where it
+    // starts with the same monkey and worry level, after which everything it
+    // does repeats too. None if the config doesn't reduce worry levels.
+    pub fn extrapolate(
+        &self,
+        config: &SimulationConfig,
+        rounds: u64,
+    ) -> Result<Option<Vec<(u64, u64)>>, SimulationError> {
+        let Some(modulus) = self.modulus(config)? else {
+            return Ok(None);
+        };
+        let mut counts: Vec<u64> = self.monkeys.iter().map(|m| m.inspected).collect();
+
+        for (start, monkey) in self.monkeys.iter().enumerate() {
+            for item in &monkey.items {
+                let item_counts =
+                    self.extrapolate_item(start, item, &config.relief, modulus, rounds)?;
+                for (count, extra) in counts.iter_mut().zip(item_counts) {
+                    *count += extra;
+                }
+            }
+        }
+
+        Ok(Some(
+            self.monkeys.iter().map(|m| m.id).zip(counts).collect(),
+        ))
+    }
+
+    fn extrapolate_item(
+        &self,
+        start: usize,
+        item: &W,
+        relief: &Expression,
+        modulus: u64,
+        rounds: u64,
+    ) -> Result<Vec<u64>, Overflow> {
+        // totals[r] is how often each monkey has inspected the item after r
+        // rounds, and seen says which round each state first started.
+        let mut totals = vec![vec![0; self.monkeys.len()]];
+        let mut seen: HashMap<(usize, W), u64> = HashMap::new();
+        let mut state = (start, W::from_u64(item.rem_u64(modulus)));
+
+        for round in 0..rounds {
+            if let Some(&cycle_start) = seen.get(&state) {
+                let period = round - cycle_start;
+                let (cycles, remainder) = (
+                    (rounds - cycle_start) / period,
+                    (rounds - cycle_start) % period,
+                );
+                let (first, last) = (&totals[cycle_start as usize], &totals[round as usize]);
+                let partial = &totals[(cycle_start + remainder) as usize];
+
+                return Ok(partial
+                    .iter()
+                    .zip(first.iter().zip(last))
+                    .map(|(partial, (first, last))| partial + cycles * (last - first))
+                    .collect());
+            }
+            seen.insert(state.clone(), round);
+
+            // Thrown to a monkey later in the round, the item gets inspected
+            // again this round; thrown backwards, it waits for the next one.
+            let mut total = totals[round as usize].clone();
+            let (mut i, mut worry) = state;
+            loop {
+                total[i] += 1;
+                let (next_worry, target) = self.inspect(i, &worry, relief, Some(modulus))?;
+                worry = next_worry;
+                if target <= i {
+                    state = (target, worry);
+                    break;
+                }
+                i = target;
+            }
+            totals.push(total);
+        }
+
+        Ok(totals.pop().unwrap())
+    }
+
+    // How many items each monkey has inspected so far, by id.
+    pub fn inspections(&self) -> Vec<(u64, u64)> {
+        self.monkeys.iter().map(|m| (m.id, m.inspected)).collect()
+    }
+
+    pub fn monkey_business(&self, top: usize) -> u128 {
+        monkey_business(&self.inspections(), top)
+    }
+}
+
+// Recording worry levels only makes sense while they're small.
+impl MonkeyTroop<u64> {
+    // Like `run`, but keeps a record of every round.
+    pub fn run_recorded(&mut self, config: &SimulationConfig) -> Result<History, SimulationError> {
+        let modulus = self.modulus(config)?;
+        let mut rounds = vec![];
+
+        for round in 1..=config.rounds {
+            let before = self.inspections();
+            let mut transfers = vec![];
+            self.round(&config.relief, modulus, |from, to, worry| {
+                transfers.push(Transfer {
+                    from,
+                    to,
+                    worry: *worry,
+                })
+            })?;
+
+            let inspections = self
+                .inspections()
+                .iter()
+                .zip(before)
+                .map(|(&(monkey, after), (_, before))| Inspection {
+                    monkey,
+                    inspected: after - before,
+                })
+                .collect();
+            rounds.push(Round {
+                round,
+                transfers,
+                inspections,
+            });
+        }
+
+        Ok(History { rounds })
+    }
+}
+
+// The product of the `top` highest inspection counts, which can get large
+// for extrapolated counts.
+pub fn monkey_business(inspections: &[(u64, u64)], top: usize) -> u128 {
+    let mut counts: Vec<u64> = inspections.iter().map(|(_, count)| *count).collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    counts
+        .iter()
+        .take(top)
+        .map(|count| *count as u128)
+        .product()
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, parse, Solution};
+
+    use num_bigint::BigUint;
+
+    use crate::Day11;
+
+    use super::*;
+
+    fn sample() -> MonkeyTroop {
+        MonkeyTroop::new(&Day11::parse(&read("sample.txt").unwrap()).unwrap())
+    }
+
+    #[test]
+    fn test_inspections() {
+        let mut troop = sample();
+        troop.run(&SimulationConfig::part1()).unwrap();
+        assert_eq!(
+            vec![(0, 101), (1, 95), (2, 7), (3, 105)],
+            troop.inspections()
+        );
+        assert_eq!(105 * 101 * 95, troop.monkey_business(3));
+
+        let mut troop = sample();
+        troop
+            .run(&SimulationConfig {
+                rounds: 20,
+                ..SimulationConfig::part2()
+            })
+            .unwrap();
+        assert_eq!(
+            vec![(0, 99), (1, 97), (2, 8), (3, 103)],
+            troop.inspections()
+        );
+    }
+
+    #[test]
+    fn test_modulus() {
+        let troop = sample();
+        let config = |reduction| SimulationConfig {
+            reduction,
+            ..SimulationConfig::part2()
+        };
+        assert_eq!(Ok(None), troop.modulus(&config(Reduction::None)));
+        assert_eq!(
+            Ok(Some(23 * 19 * 13 * 17)),
+            troop.modulus(&config(Reduction::Product))
+        );
+        assert_eq!(
+            Ok(Some(23 * 19 * 13 * 17)),
+            troop.modulus(&config(Reduction::Lcm))
+        );
+        assert_eq!(Some(12), lcm(4, 6));
+
+        let err = troop
+            .modulus(&SimulationConfig {
+                reduction: Reduction::Lcm,
+                ..SimulationConfig::part1()
+            })
+            .unwrap_err();
+        assert_eq!(
+            SimulationError::Unreducible("the relief rule new = old / 3".to_string()),
+            err
+        );
+
+        let mut specs = Day11::parse(&read("sample.txt").unwrap()).unwrap();
+        specs[0].divisible_by = (1 << 32) + 15;
+        specs[1].divisible_by = (1 << 32) + 13;
+        let troop: MonkeyTroop = MonkeyTroop::new(&specs);
+        for reduction in [Reduction::Product, Reduction::Lcm] {
+            assert_eq!(
+                Err(SimulationError::ModulusOverflow(reduction)),
+                troop.modulus(&config(reduction))
+            );
+        }
+
+        let mut specs = Day11::parse(&read("sample.txt").unwrap()).unwrap();
+        specs[1].operation = parse::line("old / 2 + 3", Expression::parse).unwrap();
+        let mut troop: MonkeyTroop = MonkeyTroop::new(&specs);
+        let err = troop.run(&SimulationConfig::part2()).unwrap_err();
+        assert!(err.to_string().contains("monkey 1's operation"));
+        assert!(troop.extrapolate(&SimulationConfig::part2(), 50).is_err());
+    }
+
+    #[test]
+    fn test_history() {
+        let mut troop = sample();
+        let history = troop
+            .run_recorded(&SimulationConfig {
+                rounds: 2,
+                ..SimulationConfig::part1()
+            })
+            .unwrap();
+
+        let first = &history.rounds[0];
+        assert_eq!(
+            Transfer {
+                from: 0,
+                to: 3,
+                worry: 500
+            },
+            first.transfers[0]
+        );
+        let inspected: Vec<u64> = first.inspections.iter().map(|i| i.inspected).collect();
+        assert_eq!(vec![2, 4, 3, 5], inspected);
+
+        let csv = history.to_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!("round,kind,monkey,to,worry,inspected", rows[0]);
+        assert_eq!("1,transfer,0,3,500,", rows[1]);
+        assert_eq!("1,inspection,3,,,5", rows[1 + 14 + 3]);
+        assert_eq!("2,transfer,0,3,126,", rows[1 + 14 + 4]);
+        assert_eq!(1 + 14 + 4 + 16 + 4, rows.len());
+        assert!(rows.iter().all(|row| row.split(',').count() == 6));
+        assert!(history.to_json().contains("\"worry\": 500"));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let config = SimulationConfig::part2();
+        for rounds in [0, 1, 20, 1000, 10_000] {
+            let mut troop = sample();
+            let extrapolated = troop.extrapolate(&config, rounds).unwrap().unwrap();
+            troop
+                .run(&SimulationConfig {
+                    rounds: rounds as usize,
+                    ..config.clone()
+                })
+                .unwrap();
+            assert_eq!(troop.inspections(), extrapolated);
+        }
+
+        let unreduced = sample().extrapolate(&SimulationConfig::part1(), 20);
+        assert_eq!(Ok(None), unreduced);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let specs = Day11::parse(&read("sample.txt").unwrap()).unwrap();
+        let config = SimulationConfig {
+            rounds: 30,
+            relief: Expression::Old,
+            reduction: Reduction::None,
+        };
+
+        let err = MonkeyTroop::<u64>::new(&specs).run(&config).unwrap_err();
+        let SimulationError::Overflow(err) = err else {
+            panic!("expected an overflow, got {}", err);
+        };
+        assert_eq!((0, "new = old * 19"), (err.monkey, err.expression.as_str()));
+
+        let mut troop = MonkeyTroop::<BigUint>::new(&specs);
+        troop.run(&config).unwrap();
+        let mut reduced = sample();
+        reduced
+            .run(&SimulationConfig {
+                rounds: 30,
+                ..SimulationConfig::part2()
+            })
+            .unwrap();
+        assert_eq!(reduced.inspections(), troop.inspections());
+    }
+}
@@ -0,0 +1,106 @@
+use std::{fmt, hash::Hash};
+
+pub use num_bigint::BigUint;
+
+// A worry level the simulation can do arithmetic on. Every operation is
+// checked, returning None rather than wrapping or panicking.
+pub trait Worry: Clone + Eq + Hash + fmt::Debug + fmt::Display {
+    fn from_u64(value: u64) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    // `modulus` is never 0, since parsing rejects it as a divisor.
+    fn rem_u64(&self, modulus: u64) -> u64;
+}
+
+impl Worry for u64 {
+    fn from_u64(value: u64) -> Self {
+        value
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u64::checked_add(*self, *other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        u64::checked_sub(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        u64::checked_mul(*self, *other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        u64::checked_div(*self, *other)
+    }
+
+    fn rem_u64(&self, modulus: u64) -> u64 {
+        self % modulus
+    }
+}
+
+// Never overflows, but only subtracting below zero or dividing by zero fail.
+impl Worry for BigUint {
+    fn from_u64(value: u64) -> Self {
+        BigUint::from(value)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (self >= other).then(|| self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        (*other != BigUint::ZERO).then(|| self / other)
+    }
+
+    fn rem_u64(&self, modulus: u64) -> u64 {
+        u64::try_from(self % modulus).unwrap()
+    }
+}
+
+// An operation or relief rule that couldn't be worked out for an item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub monkey: u64,
+    pub expression: String,
+    pub old: String,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "monkey {} couldn't work out {} for old = {}, it overflows or divides by zero",
+            self.monkey, self.expression, self.old
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(None, Worry::checked_mul(&u64::MAX, &2));
+        assert_eq!(None, Worry::checked_sub(&1u64, &2));
+        assert_eq!(None, Worry::checked_div(&1u64, &0));
+
+        let big = BigUint::from(u64::MAX);
+        let doubled = Worry::checked_mul(&big, &BigUint::from(2u64)).unwrap();
+        assert_eq!("36893488147419103230", doubled.to_string());
+        assert_eq!(2, doubled.rem_u64(7));
+        assert_eq!(None, Worry::checked_sub(&big, &doubled));
+    }
+}
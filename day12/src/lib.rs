@@ -0,0 +1,195 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    error::ParseError,
+    geometry::Point,
+    grid::Grid,
+    input,
+    search::{self, DistanceField},
+    Answer, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+pub mod render;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    // Checks the map only holds heights a-z, one S and one E before reading it.
+    fn parse(text: &str) -> Result<Self::Input> {
+        let (mut start, mut end) = (false, false);
+
+        for (number, line) in input::numbered_lines(text) {
+            let row = line.trim_end();
+            for (i, c) in row.char_indices() {
+                let seen = match c {
+                    'S' => &mut start,
+                    'E' => &mut end,
+                    'a'..='z' => continue,
+                    _ => {
+                        let found = &row[i..i + c.len_utf8()];
+                        return Err(ParseError::at(line, found, "a height a-z, S or E")
+                            .at_line(number, line)
+                            .into());
+                    }
+                };
+
+                if *seen {
+                    let expected = format!("a height a-z, {} was already placed", c);
+                    return Err(ParseError::at(line, &row[i..i + 1], expected)
+                        .at_line(number, line)
+                        .into());
+                }
+                *seen = true;
+            }
+        }
+
+        if !start || !end {
+            return Err(anyhow!("the heightmap needs both a start S and an end E"));
+        }
+
+        let grid = Grid::from_chars(text)?;
+        Ok(Heightmap {
+            start: grid.position(|c| *c == 'S').unwrap(),
+            end: grid.position(|c| *c == 'E').unwrap(),
+            heights: grid.map(|c| match c {
+                'S' => 0,
+                'E' => 25,
+                c => *c as u8 - b'a',
+            }),
+        })
+    }
+
+    fn part1(heightmap: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(heightmap)?.into())
+    }
+
+    fn part2(heightmap: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(heightmap)?.into())
+    }
+}
+
+// Heights run from 0 for a (and S) to 25 for z (and E).
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    pub heights: Grid<u8>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Heightmap {
+    // Where you can step to from `p`: at most one higher, but any amount lower.
+    pub fn climbable(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let height = self.heights[p];
+        self.heights
+            .neighbours4(p)
+            .filter(move |n| self.heights[*n] <= height + 1)
+    }
+
+    // Where you could have stepped to `p` from, so searching with this walks
+    // the climb backwards.
+    pub fn descendable(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let height = self.heights[p];
+        self.heights
+            .neighbours4(p)
+            .filter(move |n| self.heights[*n] + 1 >= height)
+    }
+
+    // One search back down from E, which knows how far every square is from it.
+    pub fn descend(&self) -> Descent<'_> {
+        Descent {
+            heightmap: self,
+            field: search::distances([self.end], |p| self.descendable(*p)),
+        }
+    }
+}
+
+pub struct Descent<'a> {
+    heightmap: &'a Heightmap,
+    field: DistanceField<Point>,
+}
+
+impl Descent<'_> {
+    // None when E can't be reached from `p` at all.
+    pub fn steps_from(&self, p: Point) -> Option<usize> {
+        self.field.distance(&p)
+    }
+
+    // The shortest route from `p` up to E, both ends included.
+    pub fn path_from(&self, p: Point) -> Option<Vec<Point>> {
+        self.field.path_from(&p)
+    }
+
+    // The lowest square closest to E, the first in reading order on a tie.
+    pub fn best_start(&self) -> Option<Point> {
+        self.heightmap
+            .heights
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .filter_map(|(p, _)| Some((p, self.steps_from(p)?)))
+            .min_by_key(|(_, steps)| *steps)
+            .map(|(p, _)| p)
+    }
+
+    // Steps to E from every square on the map.
+    pub fn heatmap(&self) -> Grid<Option<usize>> {
+        let heights = &self.heightmap.heights;
+        let mut heatmap = Grid::filled(heights.width(), heights.height(), None);
+        for (p, _) in heights.iter() {
+            heatmap[p] = self.steps_from(p);
+        }
+        heatmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Day12::parse("Sabq\nab1E\n").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 3, "1"), (err.line, err.column, err.found.as_str()));
+
+        let err = Day12::parse("SabS\nabcE\n").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((1, 4), (err.line, err.column));
+
+        assert!(Day12::parse("Sabq\nabcd\n").is_err());
+    }
+
+    #[test]
+    fn test_descend() {
+        let heightmap = Day12::parse(&input::read("sample.txt").unwrap()).unwrap();
+        let descent = heightmap.descend();
+
+        assert_eq!(Some(31), descent.steps_from(heightmap.start));
+        assert_eq!(Some(Point::new(0, 4)), descent.best_start());
+
+        let path = descent.path_from(Point::new(0, 4)).unwrap();
+        assert_eq!((30, heightmap.end), (path.len(), *path.last().unwrap()));
+
+        let heatmap = descent.heatmap();
+        assert_eq!(Some(0), heatmap[heightmap.end]);
+        assert_eq!(Some(31), heatmap[heightmap.start]);
+        assert_eq!(40, heatmap.iter().count());
+    }
+
+    #[test]
+    fn test_wider_than_i8() {
+        let row = format!("S{}bcdefghijklmnopqrstuvwxyzE", "a".repeat(150));
+        let heightmap = Day12::parse(&row).unwrap();
+        assert_eq!(176, heightmap.end.x);
+        assert_eq!(176, part1::solve(&heightmap).unwrap());
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let heightmap = Day12::parse("SazE\n").unwrap();
+        assert!(part1::solve(&heightmap).is_err());
+        assert!(part2::solve(&heightmap).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{geometry::Point, search};
+use tracing::debug;
+
+use crate::{render, Heightmap};
+
+pub fn solve(heightmap: &Heightmap) -> Result<i64> {
+    Ok((route(heightmap)?.len() - 1) as i64)
+}
+
+// The shortest route from S to E, both ends included.
+pub fn route(heightmap: &Heightmap) -> Result<Vec<Point>> {
+    let end = heightmap.end;
+    let (path, steps) = search::astar(
+        [heightmap.start],
+        |p| heightmap.climbable(*p).map(|n| (n, 1)),
+        |p| p.manhattan(end) as u64,
+        |p| *p == end,
+    )
+    .ok_or(anyhow!("no path reaches E"))?;
+
+    debug!(
+        "Solved: {} steps\n{}",
+        steps,
+        render::arrows(heightmap, &path)
+    );
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day12;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 31;
+        let input = Day12::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
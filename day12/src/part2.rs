@@ -0,0 +1,42 @@
+use anyhow::{anyhow, Result};
+use aoc_common::geometry::Point;
+use tracing::debug;
+
+use crate::{render, Heightmap};
+
+pub fn solve(heightmap: &Heightmap) -> Result<i64> {
+    Ok((route(heightmap)?.len() - 1) as i64)
+}
+
+// Searching back down from E finds the distance to every lowest square at
+// once, so the closest one is just the smallest of them.
+pub fn route(heightmap: &Heightmap) -> Result<Vec<Point>> {
+    let descent = heightmap.descend();
+    let start = descent.best_start().ok_or(anyhow!("no path reaches E"))?;
+    let path = descent.path_from(start).unwrap();
+
+    debug!(
+        "Solved: {} steps from {}\n{}",
+        path.len() - 1,
+        start,
+        render::arrows(heightmap, &path)
+    );
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day12;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 29;
+        let input = Day12::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+use aoc_common::{
+    geometry::{Direction, Point},
+    grid::Grid,
+};
+
+use crate::Heightmap;
+
+const CELL: usize = 10;
+
+// The route drawn the way the puzzle does: an arrow on each square pointing
+// at the next one, E at the end and dots everywhere else.
+pub fn arrows(heightmap: &Heightmap, route: &[Point]) -> Grid<char> {
+    let heights = &heightmap.heights;
+    let mut grid = Grid::filled(heights.width(), heights.height(), '.');
+
+    for step in route.windows(2) {
+        grid[step[0]] = match step[1] - step[0] {
+            p if p == Direction::Up.step() => '^',
+            p if p == Direction::Right.step() => '>',
+            p if p == Direction::Down.step() => 'v',
+            p if p == Direction::Left.step() => '<',
+            _ => panic!("{} to {} isn't a single step", step[0], step[1]),
+        };
+    }
+    if let Some(last) = route.last() {
+        grid[*last] = 'E';
+    }
+
+    grid
+}
+
+// An SVG of the heightmap, dark green at a up to white at z, with the route
+// drawn over it from a circle at its start.
+pub fn svg(heightmap: &Heightmap, route: &[Point]) -> String {
+    let heights = &heightmap.heights;
+    let (width, height) = (heights.width() * CELL, heights.height() * CELL);
+    let mut svg = String::new();
+
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    )
+    .unwrap();
+
+    for (p, h) in heights.iter() {
+        writeln!(
+            svg,
+            r#"  <rect x="{}" y="{}" width="{CELL}" height="{CELL}" fill="hsl(120, 40%, {}%)"><title>{} {}</title></rect>"#,
+            p.x as usize * CELL,
+            p.y as usize * CELL,
+            15 + *h as usize * 85 / 25,
+            p,
+            (b'a' + h) as char,
+        )
+        .unwrap();
+    }
+
+    if let Some(start) = route.first() {
+        let points: Vec<String> = route
+            .iter()
+            .map(|p| format!("{},{}", centre(p.x), centre(p.y)))
+            .collect();
+        writeln!(
+            svg,
+            r#"  <polyline points="{}" fill="none" stroke="crimson" stroke-width="2"/>"#,
+            points.join(" ")
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"  <circle cx="{}" cy="{}" r="{}" fill="crimson"/>"#,
+            centre(start.x),
+            centre(start.y),
+            CELL / 3
+        )
+        .unwrap();
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn centre(coordinate: i64) -> usize {
+    coordinate as usize * CELL + CELL / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::{part1, Day12};
+
+    use super::*;
+
+    #[test]
+    fn test_arrows() {
+        let heightmap = Day12::parse(&read("sample.txt").unwrap()).unwrap();
+        let route = part1::route(&heightmap).unwrap();
+        let expected = "\
+v..v<<<<
+>v.vv<<^
+.>vv>E^^
+..v>>>^^
+..>>>>>^";
+        assert_eq!(expected, arrows(&heightmap, &route).to_string());
+    }
+
+    #[test]
+    fn test_svg() {
+        let heightmap = Day12::parse("SbcdE").unwrap();
+        let route: Vec<Point> = (0..5).map(|x| Point::new(x, 0)).collect();
+        let svg = svg(&heightmap, &route);
+        assert_eq!(5, svg.matches("<rect").count());
+        assert!(svg.contains(r#"points="5,5 15,5 25,5 35,5 45,5""#));
+    }
+}
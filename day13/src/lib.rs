@@ -0,0 +1,183 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{
+    error::ParseError,
+    input,
+    parse::{self, Cursor},
+    Answer, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    // Packets come in pairs, a blank line after each.
+    type Input = Vec<(Value, Value)>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        let mut pairs = vec![];
+        for block in input::blocks(text) {
+            if let Some(&(number, line)) = block.get(2) {
+                let found = line.trim_start();
+                return Err(
+                    ParseError::at(line, found, "a blank line after a pair of packets")
+                        .at_line(number, line)
+                        .into(),
+                );
+            }
+
+            let packet = |(number, line): (usize, &str)| {
+                line.parse::<Value>()
+                    .map_err(|err| err.at_line(number, line))
+            };
+            let (number, line) = block[0];
+            let left = packet(block[0])?;
+            let right = match block.get(1) {
+                Some(&second) => packet(second)?,
+                None => {
+                    let err =
+                        ParseError::new(line.len() + 1, "a second packet on the next line", "");
+                    return Err(err.at_line(number, line).into());
+                }
+            };
+            pairs.push((left, right));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(pairs)?.into())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(pairs).into())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Value {
+    Integer(i64),
+    Array(Vec<Value>),
+}
+
+// Packets order the way the puzzle compares them: an integer next to a list
+// is treated as a list holding just that integer, so `[[1]]` and `[1]` are
+// equal.
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+            (Value::Array(left), Value::Array(right)) => left.iter().cmp(right.iter()),
+            (Value::Integer(_), Value::Array(right)) => {
+                std::slice::from_ref(self).iter().cmp(right.iter())
+            }
+            (Value::Array(left), Value::Integer(_)) => {
+                left.iter().cmp(std::slice::from_ref(other).iter())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Integer(i) => write!(f, "{}", i),
+            Value::Array(list) => {
+                write!(f, "[")?;
+                for (i, v) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// A packet is always a list, and has to take up the whole line.
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::line(line, array)
+    }
+}
+
+fn array(cursor: &mut Cursor) -> Result<Value, ParseError> {
+    Ok(Value::Array(cursor.list("[", ",", "]", value)?))
+}
+
+fn value(cursor: &mut Cursor) -> Result<Value, ParseError> {
+    match cursor.peek() {
+        Some('[') => array(cursor),
+        _ => Ok(Value::Integer(cursor.integer("an integer or \"[\"")?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_value() {
+        let value: Value = "[[1],[2,3,4],[]]".parse().unwrap();
+        assert_eq!("[[1],[2,3,4],[]]", value.to_string());
+
+        let err = "[1,,2]".parse::<Value>().unwrap_err();
+        assert_eq!((4, ","), (err.column, err.found.as_str()));
+
+        let err = "[[1],[2".parse::<Value>().unwrap_err();
+        assert_eq!((8, "\",\" or \"]\""), (err.column, err.expected.as_str()));
+
+        let err = "1,2".parse::<Value>().unwrap_err();
+        assert_eq!((1, "\"[\""), (err.column, err.expected.as_str()));
+    }
+
+    #[test]
+    fn test_compare_values() {
+        let value = |text: &str| text.parse::<Value>().unwrap();
+        assert!(value("[1,1,3,1,1]") < value("[1,1,5,1,1]"));
+        assert!(value("[[1],[2,3,4]]") < value("[[1],4]"));
+        assert!(value("[9]") > value("[[8,7,6]]"));
+        assert!(value("[7,7,7,7]") > value("[7,7,7]"));
+        assert!(value("[]") < value("[3]"));
+        assert_eq!(value("[[1]]"), value("[1]"));
+    }
+
+    #[test]
+    fn test_parse_pairs() {
+        let pairs = Day13::parse("[1]\n[2]\n\n[3]\n[[]]\n").unwrap();
+        assert_eq!(2, pairs.len());
+        assert_eq!("[[]]", pairs[1].1.to_string());
+
+        let err = Day13::parse("[1]\n[2]\n\n[3]").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (4, 4, "a second packet on the next line"),
+            (err.line, err.column, err.expected.as_str())
+        );
+
+        let err = Day13::parse("[1]\n[2]\n[3]").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((3, "[3]"), (err.line, err.found.as_str()));
+    }
+}
@@ -0,0 +1,46 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+use tracing::trace;
+
+use crate::Value;
+
+// Identical packets don't have an order, which the puzzle never expects.
+pub fn solve(pairs: &[(Value, Value)]) -> Result<i64> {
+    let mut result = 0;
+
+    for (i, (left, right)) in pairs.iter().enumerate() {
+        trace!("Left: {}, right: {}", left, right);
+
+        match left.cmp(right) {
+            Ordering::Less => result += (i + 1) as i64,
+            Ordering::Greater => {}
+            Ordering::Equal => {
+                return Err(anyhow!(
+                    "pair {} is {} twice, so neither packet comes first",
+                    i + 1,
+                    left
+                ))
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day13;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 13;
+        let input = Day13::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,52 @@
+use tracing::{debug, trace};
+
+use crate::Value;
+
+pub fn solve(pairs: &[(Value, Value)]) -> i64 {
+    let divider_packet_1 = Value::Array(vec![Value::Array(vec![Value::Integer(2)])]);
+    let divider_packet_2 = Value::Array(vec![Value::Array(vec![Value::Integer(6)])]);
+
+    let mut packets: Vec<&Value> = vec![&divider_packet_1, &divider_packet_2];
+    for (left, right) in pairs {
+        packets.push(left);
+        packets.push(right);
+    }
+
+    packets.sort();
+    for packet in &packets {
+        trace!("Sorted: {}", packet);
+    }
+
+    // Found by identity, as an input packet can compare equal to a divider.
+    let mut index_1 = 0;
+    let mut index_2 = 0;
+    for (i, packet) in packets.iter().enumerate() {
+        if std::ptr::eq(*packet, &divider_packet_1) {
+            index_1 = i + 1;
+        }
+
+        if std::ptr::eq(*packet, &divider_packet_2) {
+            index_2 = i + 1;
+        }
+    }
+
+    debug!("Divider packet indexes: {}, {}", index_1, index_2);
+    (index_1 * index_2) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day13;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 140;
+        let input = Day13::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
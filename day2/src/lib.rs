@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(input::parse_lines(text)?)
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(rounds).into())
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(rounds).into())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HandSign {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl HandSign {
+    pub fn score(&self) -> i64 {
+        match self {
+            HandSign::Rock => 1,
+            HandSign::Paper => 2,
+            HandSign::Scissors => 3,
+        }
+    }
+
+    pub fn beats(&self) -> HandSign {
+        match self {
+            HandSign::Rock => HandSign::Scissors,
+            HandSign::Paper => HandSign::Rock,
+            HandSign::Scissors => HandSign::Paper,
+        }
+    }
+
+    pub fn loses_to(&self) -> HandSign {
+        match self {
+            HandSign::Rock => HandSign::Paper,
+            HandSign::Paper => HandSign::Scissors,
+            HandSign::Scissors => HandSign::Rock,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn of(opponent: HandSign, selfsign: HandSign) -> Outcome {
+        if opponent == selfsign {
+            Outcome::Draw
+        } else if opponent.loses_to() == selfsign {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn score(&self) -> i64 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+
+    // The sign to throw against `opponent` to get this outcome
+    pub fn sign_against(&self, opponent: HandSign) -> HandSign {
+        match self {
+            Outcome::Lose => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.loses_to(),
+        }
+    }
+}
+
+pub fn round_score(opponent: HandSign, selfsign: HandSign) -> i64 {
+    Outcome::of(opponent, selfsign).score() + selfsign.score()
+}
+
+// The second column of the strategy guide, which the two parts read
+// differently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+// A strategy guide line like "A Y".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: HandSign,
+    pub response: Column,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim_end();
+        let mut columns = line.split(' ');
+
+        let opponent = match columns.next() {
+            Some("A") => HandSign::Rock,
+            Some("B") => HandSign::Paper,
+            Some("C") => HandSign::Scissors,
+            Some(other) => return Err(ParseError::at(line, other, "A, B or C")),
+            None => return Err(ParseError::new(1, "A, B or C", "")),
+        };
+
+        let response = match columns.next() {
+            Some("X") => Column::X,
+            Some("Y") => Column::Y,
+            Some("Z") => Column::Z,
+            Some(other) => return Err(ParseError::at(line, other, "X, Y or Z")),
+            None => return Err(ParseError::new(line.len() + 1, "X, Y or Z", "")),
+        };
+
+        if let Some(extra) = columns.next() {
+            return Err(ParseError::at(line, extra, "the end of the line"));
+        }
+
+        Ok(Round { opponent, response })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round() {
+        let round: Round = "A Y".parse().unwrap();
+        assert_eq!(HandSign::Rock, round.opponent);
+        assert_eq!(Column::Y, round.response);
+
+        let err = "A Q".parse::<Round>().unwrap_err();
+        assert_eq!(3, err.column);
+        assert_eq!("X, Y or Z", err.expected);
+
+        let err = "A".parse::<Round>().unwrap_err();
+        assert_eq!(2, err.column);
+        assert_eq!("", err.found);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{round_score, Column, HandSign, Round};
+
+fn hand_sign(column: Column) -> HandSign {
+    match column {
+        Column::X => HandSign::Rock,
+        Column::Y => HandSign::Paper,
+        Column::Z => HandSign::Scissors,
+    }
+}
+
+pub fn solve(rounds: &[Round]) -> i64 {
+    rounds
+        .iter()
+        .map(|round| round_score(round.opponent, hand_sign(round.response)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day2;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 15;
+        let input = Day2::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{round_score, Column, Outcome, Round};
+
+fn outcome(column: Column) -> Outcome {
+    match column {
+        Column::X => Outcome::Lose,
+        Column::Y => Outcome::Draw,
+        Column::Z => Outcome::Win,
+    }
+}
+
+pub fn solve(rounds: &[Round]) -> i64 {
+    rounds
+        .iter()
+        .map(|round| {
+            let selfsign = outcome(round.response).sign_against(round.opponent);
+            round_score(round.opponent, selfsign)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day2;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 12;
+        let input = Day2::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    // Each line is a rucksack, and every item in it has to be a letter for it
+    // to have a priority.
+    fn parse(text: &str) -> Result<Self::Input> {
+        let mut rucksacks = vec![];
+        for (number, line) in input::numbered_lines(text) {
+            let rucksack = line.trim();
+            if let Some((i, c)) = rucksack
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_alphabetic())
+            {
+                let found = &rucksack[i..i + c.len_utf8()];
+                return Err(ParseError::at(line, found, "an item letter")
+                    .at_line(number, line)
+                    .into());
+            }
+            rucksacks.push(rucksack.to_string());
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(lines).into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(lines)?.into())
+    }
+}
@@ -1,12 +1,6 @@
-use anyhow::Result;
 use std::collections::HashMap;
 
-fn main() -> Result<()> {
-    let lines = lines("src/bin/input.txt".into())?;
-    let result = solve(lines);
-    println!("Day 3, part 1 result: {}", result);
-    Ok(())
-}
+use tracing::trace;
 
 fn priority(c: char) -> i64 {
     // a..z is 97-122
@@ -14,23 +8,21 @@ fn priority(c: char) -> i64 {
     // Abuse ASCII codes to get priority
 
     let ascii = c as u8;
-    // println!("ascii: {}", ascii);
-    if ascii >= 65 && ascii <= 90 {
+    if (65..=90).contains(&ascii) {
         return (ascii - 38) as i64;
     }
 
-    if ascii >= 97 && ascii <= 122 {
+    if (97..=122).contains(&ascii) {
         return (ascii - 96) as i64;
     }
 
     unreachable!();
 }
 
-fn solve(lines: Vec<String>) -> i64 {
+pub fn solve(lines: &[String]) -> i64 {
     let mut miscategorized_items: Vec<char> = vec![];
 
     'line: for line in lines {
-        // println!("line: {}", line);
         let mut seen = HashMap::new();
         let (first_compartment, second_compartment) = line.split_at(line.len() / 2);
 
@@ -48,35 +40,29 @@ fn solve(lines: Vec<String>) -> i64 {
 
     let mut result = 0;
     for item in miscategorized_items {
-        // println!(
-        //     "Miscategorized: {:?}, priority is {:?}",
-        //     item,
-        //     priority(item)
-        // );
+        trace!(
+            "Miscategorized: {:?}, priority is {:?}",
+            item,
+            priority(item)
+        );
         result += priority(item);
     }
     result
 }
 
-fn lines(path: String) -> Result<Vec<String>> {
-    let input_data: String = String::from_utf8(std::fs::read(path)?)?;
-    let l: Vec<String> = input_data
-        .trim()
-        .split('\n')
-        .map(|input| input.trim().to_string())
-        .filter(|input| input != "")
-        .collect();
-    Ok(l)
-}
-
 #[cfg(test)]
 mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day3;
+
     use super::*;
 
     #[test]
     fn test_solve() {
         let expected = 157;
-        let actual = solve(lines("src/bin/sample.txt".into()).unwrap());
+        let input = Day3::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
         assert_eq!(expected, actual);
     }
 }
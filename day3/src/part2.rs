@@ -1,12 +1,7 @@
-use anyhow::Result;
 use std::collections::HashMap;
 
-fn main() -> Result<()> {
-    let lines = lines("src/bin/input.txt".into())?;
-    let result = solve(lines);
-    println!("Day 3, part 1 result: {}", result);
-    Ok(())
-}
+use anyhow::{anyhow, Result};
+use tracing::trace;
 
 fn priority(c: char) -> i64 {
     // a..z is 97-122
@@ -14,24 +9,26 @@ fn priority(c: char) -> i64 {
     // Abuse ASCII codes to get priority
 
     let ascii = c as u8;
-    // println!("ascii: {}", ascii);
-    if ascii >= 65 && ascii <= 90 {
+    if (65..=90).contains(&ascii) {
         return (ascii - 38) as i64;
     }
 
-    if ascii >= 97 && ascii <= 122 {
+    if (97..=122).contains(&ascii) {
         return (ascii - 96) as i64;
     }
 
     unreachable!();
 }
 
-fn solve(lines: Vec<String>) -> i64 {
+pub fn solve(lines: &[String]) -> Result<i64> {
     let mut badges: Vec<char> = vec![];
 
     'chunk: for chunk in lines.chunks(3) {
         if chunk.len() != 3 {
-            panic!("uh oh")
+            return Err(anyhow!(
+                "expected groups of 3 rucksacks, the last group has {}",
+                chunk.len()
+            ));
         }
 
         let mut group_seen: HashMap<char, u32> = HashMap::new();
@@ -61,31 +58,25 @@ fn solve(lines: Vec<String>) -> i64 {
 
     let mut result = 0;
     for badge in badges {
-        // println!("Badge: {:?}, priority is {:?}", badge, priority(badge));
+        trace!("Badge: {:?}, priority is {:?}", badge, priority(badge));
         result += priority(badge);
     }
-    result
-}
-
-fn lines(path: String) -> Result<Vec<String>> {
-    let input_data: String = String::from_utf8(std::fs::read(path)?)?;
-    let l: Vec<String> = input_data
-        .trim()
-        .split('\n')
-        .map(|input| input.trim().to_string())
-        .filter(|input| input != "")
-        .collect();
-    Ok(l)
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day3;
+
     use super::*;
 
     #[test]
     fn test_solve() {
         let expected = 70;
-        let actual = solve(lines("src/bin/sample.txt".into()).unwrap());
+        let input = Day3::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
         assert_eq!(expected, actual);
     }
 }
@@ -0,0 +1,76 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Pair>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(input::parse_lines(text)?)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(pairs).into())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(pairs).into())
+    }
+}
+
+// The section assignments of a pair of elves, like "2-4,6-8".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pair {
+    pub first: RangeInclusive<i64>,
+    pub second: RangeInclusive<i64>,
+}
+
+fn section(line: &str, s: &str) -> Result<i64, ParseError> {
+    s.parse::<i64>()
+        .map_err(|_| ParseError::at(line, s, "a section number"))
+}
+
+fn range(line: &str, s: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(line, s, "a range like 2-4"))?;
+    Ok(section(line, start)?..=section(line, end)?)
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let trimmed = line.trim_end();
+        let (first, second) = trimmed
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(trimmed.len() + 1, "\",\"", ""))?;
+
+        Ok(Pair {
+            first: range(line, first)?,
+            second: range(line, second)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pair() {
+        let pair: Pair = "2-4,6-8".parse().unwrap();
+        assert_eq!(2..=4, pair.first);
+        assert_eq!(6..=8, pair.second);
+
+        let err = "2-4,6-x".parse::<Pair>().unwrap_err();
+        assert_eq!(7, err.column);
+        assert_eq!("x", err.found);
+    }
+}
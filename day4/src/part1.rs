@@ -0,0 +1,39 @@
+use crate::Pair;
+
+fn inclusive(
+    range1: &std::ops::RangeInclusive<i64>,
+    range2: &std::ops::RangeInclusive<i64>,
+) -> bool {
+    range1.start() <= range2.start() && range1.end() >= range2.end()
+}
+
+pub fn solve(pairs: &[Pair]) -> i64 {
+    let mut inclusives = 0;
+
+    for pair in pairs {
+        let (range1, range2) = (&pair.first, &pair.second);
+
+        if inclusive(range1, range2) || inclusive(range2, range1) {
+            inclusives += 1;
+        }
+    }
+
+    inclusives
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day4;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 2;
+        let input = Day4::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use crate::Pair;
+
+fn overlap(range1: std::ops::RangeInclusive<i64>, range2: std::ops::RangeInclusive<i64>) -> bool {
+    let range1_set: HashSet<i64> = HashSet::from_iter(range1);
+    let range2_set: HashSet<i64> = HashSet::from_iter(range2);
+
+    let intersection: Vec<&i64> = range1_set.intersection(&range2_set).collect();
+    !intersection.is_empty()
+}
+
+pub fn solve(pairs: &[Pair]) -> i64 {
+    let mut overlaps = 0;
+
+    for pair in pairs {
+        let (range1, range2) = (&pair.first, &pair.second);
+
+        if overlap(range1.clone(), range2.clone()) {
+            overlaps += 1;
+        }
+    }
+
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day4;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 4;
+        let input = Day4::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
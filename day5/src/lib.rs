@@ -0,0 +1,182 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, parse, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(Procedure::parse(text)?)
+    }
+
+    fn part1(procedure: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(procedure).into())
+    }
+
+    fn part2(procedure: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(procedure).into())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Instruction {
+    pub quantity: i64,
+    pub from: i64,
+    pub to: i64,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::line(line, |cursor| {
+            cursor.literal("move ")?;
+            let quantity = cursor.integer("a number of crates")?;
+            cursor.literal(" from ")?;
+            let from = cursor.integer("a stack number")?;
+            cursor.literal(" to ")?;
+            let to = cursor.integer("a stack number")?;
+
+            Ok(Instruction { quantity, from, to })
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Stack {
+    containers: VecDeque<char>,
+}
+
+impl Stack {
+    pub fn push_front(&mut self, letter: char) {
+        self.containers.push_front(letter);
+    }
+
+    pub fn push_back(&mut self, letter: char) {
+        self.containers.push_back(letter);
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        self.containers.pop_front()
+    }
+
+    pub fn front(&self) -> Option<char> {
+        self.containers.front().copied()
+    }
+}
+
+// The starting crate diagram and the rearrangement to run on it.
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    pub stacks: HashMap<usize, Stack>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Procedure {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut stacks: HashMap<usize, Stack> = HashMap::new();
+        let mut stack_count = 0;
+        let mut instructions = vec![];
+        let mut parsing_stacks = true;
+
+        for (number, line) in input::numbered_lines(text) {
+            if line.starts_with(" 1") {
+                parsing_stacks = false;
+                stack_count = line.split_whitespace().count();
+                continue;
+            }
+
+            if parsing_stacks {
+                parse_crates(&mut stacks, line).map_err(|e| e.at_line(number, line))?;
+            } else {
+                let instruction: Instruction = line
+                    .parse()
+                    .map_err(|e: ParseError| e.at_line(number, line))?;
+                // "move 1 from 2 to 1": the stacks are the fourth and sixth words
+                for (stack, word) in [(instruction.from, 3), (instruction.to, 5)] {
+                    if stack < 1 || stack > stack_count as i64 {
+                        let found = line.split_whitespace().nth(word).unwrap();
+                        let expected = format!("a stack between 1 and {}", stack_count);
+                        return Err(ParseError::at(line, found, expected).at_line(number, line));
+                    }
+                }
+                instructions.push(instruction);
+            }
+        }
+
+        // Stacks that start out empty still need to exist to be moved onto.
+        for index in 1..=stack_count {
+            stacks.entry(index).or_insert(Stack {
+                containers: VecDeque::new(),
+            });
+        }
+
+        Ok(Procedure {
+            stacks,
+            instructions,
+        })
+    }
+}
+
+// One row of the crate diagram, four characters per stack: "[A] " or "    ".
+fn parse_crates(stacks: &mut HashMap<usize, Stack>, line: &str) -> Result<(), ParseError> {
+    parse::line(line, |cursor| {
+        for index in 1.. {
+            if !cursor.eat("   ") {
+                let letter = cursor.bracketed("[", "]", |cursor| {
+                    cursor.char("a crate letter", |c| c.is_ascii_uppercase())
+                })?;
+                stacks
+                    .entry(index)
+                    .or_insert(Stack {
+                        containers: VecDeque::new(),
+                    })
+                    .push_back(letter);
+            }
+
+            if cursor.at_end() {
+                break;
+            }
+            cursor.literal(" ")?;
+        }
+        Ok(())
+    })
+}
+
+pub fn top_crates(stacks: &HashMap<usize, Stack>) -> String {
+    let mut keys: Vec<&usize> = stacks.keys().collect();
+    keys.sort();
+    let mut result = String::new();
+    for key in keys {
+        let top = stacks.get(key).unwrap().front().unwrap_or(' ');
+        result.extend([top]);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Procedure::parse("[A] [B]\n 1   2\nmove 1 from 3 to 1\n").unwrap_err();
+        assert_eq!((3, 13), (err.line, err.column));
+        assert_eq!("a stack between 1 and 2", err.expected);
+
+        let err = Procedure::parse("[A] {B}\n 1   2\n").unwrap_err();
+        assert_eq!((1, 5), (err.line, err.column));
+
+        let err = Procedure::parse("[A]\n 1\nmove one from 1 to 1\n").unwrap_err();
+        assert_eq!((3, 6, "one"), (err.line, err.column, err.found.as_str()));
+    }
+}
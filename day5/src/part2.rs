@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::{top_crates, Instruction, Procedure, Stack};
+
+fn run_instruction(stacks: &mut HashMap<usize, Stack>, instruction: &Instruction) {
+    let mut tmp_stack: Vec<Option<char>> = vec![];
+    for _ in 0..instruction.quantity {
+        let letter = stacks.get_mut(&(instruction.from as usize)).unwrap().pop();
+        tmp_stack.push(letter);
+    }
+    tmp_stack.reverse();
+
+    for letter in tmp_stack.into_iter().flatten() {
+        stacks
+            .get_mut(&(instruction.to as usize))
+            .unwrap()
+            .push_front(letter);
+    }
+}
+
+pub fn solve(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+
+    for instruction in &procedure.instructions {
+        run_instruction(&mut stacks, instruction);
+    }
+
+    top_crates(&stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day5;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = "MCD";
+        let input = Day5::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use aoc_common::{input, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(input::single_line(text)?)
+    }
+
+    fn part1(line: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(line)?.into())
+    }
+
+    fn part2(line: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(line)?.into())
+    }
+}
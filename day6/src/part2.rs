@@ -2,13 +2,6 @@ use std::collections::{HashMap, VecDeque};
 
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let lines = lines("src/bin/input.txt".into())?;
-    let result = solve(lines)?;
-    println!("Day 6, part 2 result: {}", result);
-    Ok(())
-}
-
 struct Buffer {
     inner: VecDeque<char>,
 }
@@ -29,7 +22,7 @@ impl Buffer {
 
         let mut seen = HashMap::new();
         for c in &self.inner {
-            if let Some(_) = seen.get(&c) {
+            if seen.contains_key(&c) {
                 return false;
             }
 
@@ -40,8 +33,7 @@ impl Buffer {
     }
 }
 
-fn solve(lines: Vec<String>) -> Result<i64> {
-    let line = lines.first().unwrap();
+pub fn solve(line: &str) -> Result<i64> {
     let mut b = Buffer {
         inner: VecDeque::new(),
     };
@@ -55,24 +47,19 @@ fn solve(lines: Vec<String>) -> Result<i64> {
     Err(anyhow::anyhow!("didn't find the marker"))
 }
 
-fn lines(path: String) -> Result<Vec<String>> {
-    let input_data: String = String::from_utf8(std::fs::read(path)?)?;
-    let l: Vec<String> = input_data
-        .split('\n')
-        .map(|input| input.to_string())
-        .filter(|input| input != "")
-        .collect();
-    Ok(l)
-}
-
 #[cfg(test)]
 mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day6;
+
     use super::*;
 
     #[test]
     fn test_solve() {
         let expected = 19;
-        let actual = solve(lines("src/bin/sample.txt".into()).unwrap()).unwrap();
+        let input = Day6::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
         assert_eq!(expected, actual);
     }
 }
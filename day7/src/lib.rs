@@ -0,0 +1,197 @@
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{error::ParseError, input, parse, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = State;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(State::parse(text)?)
+    }
+
+    fn part1(state: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(state).into())
+    }
+
+    fn part2(state: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(state)?.into())
+    }
+}
+
+// One line of the terminal session: a command, or a line of ls output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TerminalLine {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(usize),
+}
+
+impl FromStr for TerminalLine {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse::line(line, |cursor| {
+            cursor.skip_whitespace();
+            if cursor.eat("$") {
+                cursor.skip_whitespace();
+                return match cursor.keyword(&["cd", "ls"])? {
+                    "cd" => {
+                        cursor.skip_whitespace();
+                        let dir = cursor.word("a directory")?;
+                        Ok(TerminalLine::Cd(dir.to_string()))
+                    }
+                    _ => Ok(TerminalLine::Ls),
+                };
+            }
+
+            if cursor.keyword(&["dir"]).is_ok() {
+                cursor.skip_whitespace();
+                let name = cursor.word("a directory name")?;
+                return Ok(TerminalLine::Dir(name.to_string()));
+            }
+
+            let size = cursor.integer("a file size or \"dir\"")?;
+            cursor.skip_whitespace();
+            cursor.word("a file name")?;
+            Ok(TerminalLine::File(size))
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Directory {
+    files: Vec<File>,
+    directories: Vec<String>,
+}
+
+impl Directory {
+    pub fn size(&self, tree: &HashMap<String, Directory>) -> usize {
+        let mut result = 0;
+        for file in &self.files {
+            result += file.size;
+        }
+
+        for dirname in &self.directories {
+            let other_dir = tree.get(dirname).unwrap();
+            result += other_dir.size(tree);
+        }
+
+        result
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    size: usize,
+}
+
+// The filesystem as pieced together from the terminal session.
+#[derive(Debug)]
+pub struct State {
+    pwd: PathBuf,
+    pub tree: HashMap<String, Directory>,
+}
+
+impl State {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut listing = false;
+        let mut state = State {
+            pwd: PathBuf::from("/"),
+            tree: HashMap::new(),
+        };
+        state.add_dir("/");
+
+        for (number, line) in input::numbered_lines(text) {
+            let at_line = |e: ParseError| e.at_line(number, line);
+
+            match line.parse::<TerminalLine>().map_err(at_line)? {
+                TerminalLine::Cd(dir) => {
+                    listing = false;
+                    if !state.cd(&dir) {
+                        let found = line.split_whitespace().nth(2).unwrap();
+                        return Err(at_line(ParseError::at(
+                            line,
+                            found,
+                            "a directory listed by ls",
+                        )));
+                    }
+                }
+                TerminalLine::Ls => listing = true,
+                TerminalLine::Dir(_) | TerminalLine::File(_) if !listing => {
+                    return Err(at_line(ParseError::at(
+                        line,
+                        line.trim_start(),
+                        "a command, since ls wasn't run",
+                    )));
+                }
+                TerminalLine::Dir(dirname) => state.add_dir(&dirname),
+                TerminalLine::File(size) => state.add_file(size),
+            }
+        }
+
+        Ok(state)
+    }
+
+    // Returns false if `dir` isn't a directory we know about.
+    fn cd(&mut self, dir: &str) -> bool {
+        let pwd = match dir {
+            ".." => self.pwd.parent().unwrap_or(&self.pwd).to_path_buf(),
+            "/" => PathBuf::from("/"),
+            _ => self.pwd.join(dir),
+        };
+
+        if !self.tree.contains_key(pwd.to_str().unwrap()) {
+            return false;
+        }
+        self.pwd = pwd;
+        true
+    }
+
+    fn add_dir(&mut self, dirname: &str) {
+        let current_path = self.pwd.to_str().unwrap();
+        let full_dir_path = self.pwd.join(dirname);
+        let full_dirname = full_dir_path.to_str().unwrap();
+
+        if dirname != "/" {
+            let parent = self.tree.get_mut(current_path).unwrap();
+            parent.directories.push(full_dirname.to_string());
+        }
+
+        let dir = Directory {
+            directories: vec![],
+            files: vec![],
+        };
+        self.tree.insert(full_dirname.to_string(), dir);
+    }
+
+    fn add_file(&mut self, size: usize) {
+        let dir = self.tree.get_mut(self.pwd.to_str().unwrap()).unwrap();
+        dir.files.push(File { size })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = State::parse("$ cd /\n$ rm -rf a\n").unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("rm", err.found);
+
+        let err = State::parse("$ cd /\n14848514 b.txt\n").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+
+        let err = State::parse("$ cd /\n$ ls\ndir a\n$ cd b\n").unwrap_err();
+        assert_eq!((4, 6), (err.line, err.column));
+        assert_eq!("a directory listed by ls", err.expected);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::State;
+
+pub fn solve(state: &State) -> usize {
+    let mut result = 0;
+    for key in state.tree.keys() {
+        let dir = state.tree.get(key).unwrap();
+        let size = dir.size(&state.tree);
+
+        if size <= 100000 {
+            result += size;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day7;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 95437;
+        let input = Day7::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+
+use crate::State;
+
+// The size of the smallest directory that frees up enough space, or 0 if
+// there's already enough.
+pub fn solve(state: &State) -> Result<usize> {
+    let total_space: usize = 70000000;
+    let min_unused_space: usize = 30000000;
+
+    let root_dir = state.tree.get("/").unwrap();
+    let used_space = root_dir.size(&state.tree);
+    let current_unused_space = total_space.checked_sub(used_space).ok_or_else(|| {
+        anyhow!(
+            "the files take up {}, more than the disk's {}",
+            used_space,
+            total_space
+        )
+    })?;
+    let Some(min_free_size) = min_unused_space.checked_sub(current_unused_space) else {
+        return Ok(0);
+    };
+
+    let mut smallest_viable_free_size = total_space;
+    for key in state.tree.keys() {
+        let dir = state.tree.get(key).unwrap();
+        let size = dir.size(&state.tree);
+
+        if size > min_free_size && size < smallest_viable_free_size {
+            smallest_viable_free_size = size;
+        }
+    }
+
+    Ok(smallest_viable_free_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day7;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 24933642;
+        let input = Day7::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_enough_space() {
+        let input = Day7::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+        assert_eq!(0, solve(&input).unwrap());
+
+        let input = Day7::parse("$ cd /\n$ ls\n70000001 a\n").unwrap();
+        assert!(solve(&input).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use aoc_common::{grid::Grid, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u32>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(Grid::from_digits(text)?)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(grid).into())
+    }
+}
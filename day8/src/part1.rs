@@ -0,0 +1,35 @@
+use aoc_common::{
+    geometry::{Direction, Point},
+    grid::Grid,
+};
+
+// A tree is visible if every tree between it and some edge is shorter. Trees
+// on the edge have nothing in the way.
+fn visible(grid: &Grid<u32>, p: Point) -> bool {
+    let tree = grid[p];
+    Direction::ORTHOGONAL.iter().any(|direction| {
+        grid.ray(p, *direction)
+            .all(|(_, other_tree)| *other_tree < tree)
+    })
+}
+
+pub fn solve(grid: &Grid<u32>) -> usize {
+    grid.points().filter(|p| visible(grid, *p)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day8;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 21;
+        let input = Day8::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
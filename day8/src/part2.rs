@@ -0,0 +1,49 @@
+use aoc_common::{
+    geometry::{Direction, Point},
+    grid::Grid,
+};
+
+// How many trees can be seen from `p` looking in `direction`, stopping at the
+// first one at least as tall.
+fn viewing_distance(grid: &Grid<u32>, p: Point, direction: Direction) -> usize {
+    let tree = grid[p];
+    let mut distance = 0;
+    for (_, other_tree) in grid.ray(p, direction) {
+        distance += 1;
+        if *other_tree >= tree {
+            break;
+        }
+    }
+    distance
+}
+
+fn scenic_score(grid: &Grid<u32>, p: Point) -> usize {
+    Direction::ORTHOGONAL
+        .iter()
+        .map(|direction| viewing_distance(grid, p, *direction))
+        .product()
+}
+
+pub fn solve(grid: &Grid<u32>) -> usize {
+    grid.points()
+        .map(|p| scenic_score(grid, p))
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day8;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 8;
+        let input = Day8::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
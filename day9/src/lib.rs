@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::{
+    error::ParseError,
+    geometry::{Direction, Point},
+    grid::Grid,
+    input, Answer, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(text: &str) -> Result<Self::Input> {
+        Ok(input::parse_lines(text)?)
+    }
+
+    fn part1(moves: &Self::Input) -> Result<Answer> {
+        Ok(part1::solve(moves).into())
+    }
+
+    fn part2(moves: &Self::Input) -> Result<Answer> {
+        Ok(part2::solve(moves).into())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub direction: Direction,
+    pub quantity: u32,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut parts = line.split_whitespace();
+
+        let direction = match parts.next() {
+            Some("U") => Direction::Up,
+            Some("D") => Direction::Down,
+            Some("L") => Direction::Left,
+            Some("R") => Direction::Right,
+            Some(other) => return Err(ParseError::at(line, other, "U, D, L or R")),
+            None => return Err(ParseError::new(1, "U, D, L or R", "")),
+        };
+
+        let quantity = match parts.next() {
+            Some(quantity) => quantity
+                .parse::<u32>()
+                .map_err(|_| ParseError::at(line, quantity, "a number of steps"))?,
+            None => {
+                let column = line.trim_end().len() + 2;
+                return Err(ParseError::new(column, "a number of steps", ""));
+            }
+        };
+
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::at(line, extra, "the end of the line"));
+        }
+
+        Ok(Move {
+            direction,
+            quantity,
+        })
+    }
+}
+
+// Draws the rope, head first, on a board just big enough for it and the
+// starting point.
+pub fn board(knots: &[Point]) -> Grid<char> {
+    let points = knots.iter().chain([&Point::ORIGIN]);
+    let min_x = points.clone().map(|p| p.x).min().unwrap();
+    let max_x = points.clone().map(|p| p.x).max().unwrap();
+    let min_y = points.clone().map(|p| p.y).min().unwrap();
+    let max_y = points.map(|p| p.y).max().unwrap();
+
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let top_left = Point::new(min_x, min_y);
+    let mut board = Grid::filled(width, height, '.');
+    board[Point::ORIGIN - top_left] = 's';
+
+    for (i, knot) in knots.iter().enumerate().rev() {
+        let label = match i {
+            0 => 'H',
+            _ if i == knots.len() - 1 => 'T',
+            _ => char::from_digit(i as u32, 36).unwrap_or('#'),
+        };
+        board[*knot - top_left] = label;
+    }
+
+    board
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_move() {
+        assert_eq!(
+            Move {
+                direction: Direction::Left,
+                quantity: 12
+            },
+            "L 12".parse().unwrap()
+        );
+
+        let err = "X 4".parse::<Move>().unwrap_err();
+        assert_eq!((1, "X"), (err.column, err.found.as_str()));
+
+        let err = "U four".parse::<Move>().unwrap_err();
+        assert_eq!((3, "four"), (err.column, err.found.as_str()));
+
+        let err = "U -5".parse::<Move>().unwrap_err();
+        assert_eq!(
+            (3, "a number of steps"),
+            (err.column, err.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_board() {
+        let knots = [Point::new(2, -1), Point::new(1, -1), Point::new(1, 0)];
+        assert_eq!(".1H\nsT.", board(&knots).to_string());
+        assert_eq!("..H\nsT.", board(&[knots[0], knots[2]]).to_string());
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use aoc_common::geometry::Point;
+use tracing::trace;
+
+use crate::{board, Move};
+
+pub fn solve(moves: &[Move]) -> i64 {
+    let mut visited: HashMap<Point, bool> = HashMap::new();
+
+    let mut head_position = Point::ORIGIN;
+    let mut tail_position = Point::ORIGIN;
+
+    visited.insert(tail_position, true);
+
+    for m in moves {
+        head_position += m.direction.step() * i64::from(m.quantity);
+        while !tail_position.is_touching(head_position) {
+            tail_position = tail_position.step_towards(head_position);
+            visited.insert(tail_position, true);
+        }
+        trace!("After {:?}:\n{}", m, board(&[head_position, tail_position]));
+    }
+
+    visited.keys().len() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day9;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 13;
+        let input = Day9::parse(&read("sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
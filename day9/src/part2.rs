@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use aoc_common::geometry::Point;
+use tracing::trace;
+
+use crate::{board, Move};
+
+struct Rope {
+    head: Point,
+    middle: Vec<Point>,
+    tail: Point,
+}
+
+impl Rope {
+    fn knots(&self) -> Vec<Point> {
+        let mut knots = vec![self.head];
+        knots.extend(&self.middle);
+        knots.push(self.tail);
+        knots
+    }
+}
+
+pub fn solve(moves: &[Move]) -> i64 {
+    let mut visited: HashMap<Point, bool> = HashMap::new();
+
+    let mut rope = Rope {
+        head: Point::ORIGIN,
+        middle: vec![Point::ORIGIN; 8],
+        tail: Point::ORIGIN,
+    };
+
+    visited.insert(rope.tail, true);
+
+    for m in moves {
+        for _ in 0..m.quantity {
+            rope.head += m.direction;
+
+            for i in 0..rope.middle.len() {
+                let prev_knot = if i == 0 {
+                    rope.head
+                } else {
+                    rope.middle[i - 1]
+                };
+
+                while !rope.middle[i].is_touching(prev_knot) {
+                    rope.middle[i] = rope.middle[i].step_towards(prev_knot);
+                }
+            }
+
+            let last_middle = *rope.middle.last().unwrap();
+            while !rope.tail.is_touching(last_middle) {
+                rope.tail = rope.tail.step_towards(last_middle);
+                visited.insert(rope.tail, true);
+            }
+        }
+        trace!("After {:?}:\n{}", m, board(&rope.knots()));
+    }
+
+    visited.keys().len() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::read, Solution};
+
+    use crate::Day9;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let expected = 36;
+        let input = Day9::parse(&read("larger-sample.txt").unwrap()).unwrap();
+        let actual = solve(&input);
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,3 +0,0 @@
-module github.com/mcasper/advent-of-code-2022
-
-go 1.16